@@ -0,0 +1,182 @@
+//! Send+Sync pointer wrapper whose alignment is checked on construction.
+
+use crate::{PtrError, SyncConstPtr, SyncMutPtr};
+use core::fmt::{Formatter, Pointer};
+
+///
+/// Wrapped mutable raw pointer that is Send+Sync and whose address is a multiple of `ALIGN`.
+///
+/// `ALIGN` must be a power of two that is at least the alignment of `T`.
+/// This is checked at compile time, for example `AlignedSyncPtr<u64, 4>` fails to compile.
+///
+/// ```compile_fail
+/// let _ = sync_ptr::AlignedSyncPtr::<u64, 4>::dangling();
+/// ```
+///
+/// Because the alignment is checked when the pointer is created,
+/// `read` and `write` can always use the aligned access path.
+///
+#[repr(transparent)]
+pub struct AlignedSyncPtr<T, const ALIGN: usize>(SyncMutPtr<T>);
+
+impl<T, const ALIGN: usize> AlignedSyncPtr<T, ALIGN> {
+    /// Compile time check that `ALIGN` is a power of two that is at least the alignment of `T`.
+    const ALIGN_VALID: () = assert!(
+        ALIGN.is_power_of_two() && ALIGN >= align_of::<T>(),
+        "ALIGN must be a power of two that is at least the alignment of T"
+    );
+
+    ///
+    /// Returns `ptr` as an aligned pointer or `None` if the address of `ptr` is not a multiple of `ALIGN`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn new(ptr: SyncMutPtr<T>) -> Option<Self> {
+        let () = Self::ALIGN_VALID;
+        if ptr.is_aligned_to(ALIGN) {
+            Some(Self(ptr))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns `ptr` as an aligned pointer.
+    ///
+    /// # Errors
+    /// Returns `PtrError::Misaligned` if the address of `ptr` is not a multiple of `ALIGN`.
+    ///
+    #[inline(always)]
+    pub fn try_new(ptr: SyncMutPtr<T>) -> Result<Self, PtrError> {
+        Self::new(ptr).ok_or_else(|| PtrError::Misaligned {
+            addr: ptr.addr(),
+            align: ALIGN,
+        })
+    }
+
+    ///
+    /// Returns `ptr` as an aligned pointer without checking its alignment.
+    ///
+    /// # Safety
+    /// The address of `ptr` must be a multiple of `ALIGN`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new_unchecked(ptr: SyncMutPtr<T>) -> Self {
+        let () = Self::ALIGN_VALID;
+        Self(ptr)
+    }
+
+    ///
+    /// Makes a Send+Sync dangling ptr whose address is `ALIGN`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn dangling() -> Self {
+        let () = Self::ALIGN_VALID;
+        Self(SyncMutPtr::without_provenance(ALIGN))
+    }
+
+    ///
+    /// Returns the wrapped pointer, which is then no longer guaranteed to be aligned.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn ptr(&self) -> SyncMutPtr<T> {
+        self.0
+    }
+
+    ///
+    /// Returns the wrapped pointer as immutable, which is then no longer guaranteed to be aligned.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_sync_const(self) -> SyncConstPtr<T> {
+        self.0.into_const()
+    }
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send+Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *mut T {
+        self.0.inner()
+    }
+
+    ///
+    /// Casts `ptr` to another data type, the pointer stays aligned to `ALIGN`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn cast<Y>(&self) -> AlignedSyncPtr<Y, ALIGN> {
+        let () = AlignedSyncPtr::<Y, ALIGN>::ALIGN_VALID;
+        AlignedSyncPtr(self.0.cast())
+    }
+
+    ///
+    /// Reads the value from `ptr` without moving it.
+    ///
+    /// # Safety
+    /// Same as `pointer::read`, except that `ptr` is known to be aligned.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn read(&self) -> T {
+        self.0.read()
+    }
+
+    ///
+    /// Overwrites the memory at `ptr` with `val` without reading or dropping the old value.
+    ///
+    /// # Safety
+    /// Same as `pointer::write`, except that `ptr` is known to be aligned.
+    ///
+    #[inline(always)]
+    pub const unsafe fn write(&self, val: T) {
+        self.0.write(val);
+    }
+}
+
+impl<T, const ALIGN: usize> Clone for AlignedSyncPtr<T, ALIGN> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const ALIGN: usize> Copy for AlignedSyncPtr<T, ALIGN> {}
+
+impl<T, const ALIGN: usize> Pointer for AlignedSyncPtr<T, ALIGN> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T, const ALIGN: usize> Eq for AlignedSyncPtr<T, ALIGN> {}
+impl<T, const ALIGN: usize> PartialEq for AlignedSyncPtr<T, ALIGN> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&self.0, &other.0)
+    }
+}
+
+impl<T, const ALIGN: usize> core::fmt::Debug for AlignedSyncPtr<T, ALIGN> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AlignedSyncPtr")
+            .field(&self.0.inner())
+            .finish()
+    }
+}
+
+impl<T, const ALIGN: usize> core::hash::Hash for AlignedSyncPtr<T, ALIGN> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.0, state);
+    }
+}
+
+impl<T, const ALIGN: usize> From<AlignedSyncPtr<T, ALIGN>> for SyncMutPtr<T> {
+    #[inline(always)]
+    fn from(val: AlignedSyncPtr<T, ALIGN>) -> Self {
+        val.ptr()
+    }
+}
@@ -0,0 +1,266 @@
+//! Atomic cell for Send+Sync pointers.
+
+use crate::{SyncConstPtr, SyncMutPtr};
+use core::fmt::Formatter;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+///
+/// Atomic pointer cell that loads and stores `SyncMutPtr`.
+///
+/// This has the same in-memory representation as `AtomicPtr<T>`.
+/// Every method forwards to the method of the same name on `AtomicPtr<T>`,
+/// but takes and returns the wrapped pointer instead of the raw pointer.
+///
+#[repr(transparent)]
+pub struct SyncAtomicPtr<T>(AtomicPtr<T>);
+
+impl<T> SyncAtomicPtr<T> {
+    ///
+    /// Creates a new atomic cell holding `ptr`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(ptr: SyncMutPtr<T>) -> Self {
+        Self(AtomicPtr::new(ptr.inner()))
+    }
+
+    ///
+    /// Creates a new atomic cell holding a null ptr.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn null() -> Self {
+        Self(AtomicPtr::new(core::ptr::null_mut()))
+    }
+
+    ///
+    /// Makes the pointer stored in `atomic` Send+Sync.
+    ///
+    /// # Safety
+    /// The pointer stored in `atomic`, and every pointer stored in it afterwards,
+    /// must be able to handle being sent and used in other threads concurrently,
+    /// or special care must be taken when using the loaded pointers to not use them
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_atomic(atomic: AtomicPtr<T>) -> Self {
+        Self(atomic)
+    }
+
+    ///
+    /// Returns the inner `AtomicPtr` whose pointer is then no longer Send+Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_atomic(self) -> AtomicPtr<T> {
+        self.0
+    }
+
+    ///
+    /// Consumes the atomic cell and returns the contained pointer.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_inner(self) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.into_inner())
+    }
+
+    ///
+    /// Returns a mutable reference to the contained pointer.
+    ///
+    /// This is safe because the mutable reference guarantees that no other threads are
+    /// concurrently accessing the atomic data.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut SyncMutPtr<T> {
+        let ptr: *mut *mut T = self.0.get_mut();
+        // SAFETY: `Ptr` is repr(transparent) over its `*const T` field, the other field is a ZST
+        // `PhantomData`, and `*const T` has the same layout as `*mut T`. Every `*mut T` is a valid
+        // `SyncMutPtr<T>`.
+        unsafe { &mut *ptr.cast::<SyncMutPtr<T>>() }
+    }
+
+    ///
+    /// Loads the contained pointer.
+    ///
+    /// # Panics
+    /// Panics if `order` is `Release` or `AcqRel`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn load(&self, order: Ordering) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.load(order))
+    }
+
+    ///
+    /// Loads the contained pointer as an immutable pointer.
+    ///
+    /// # Panics
+    /// Panics if `order` is `Release` or `AcqRel`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn load_const(&self, order: Ordering) -> SyncConstPtr<T> {
+        SyncConstPtr::wrap(self.0.load(order))
+    }
+
+    ///
+    /// Stores `ptr` in the atomic cell.
+    ///
+    /// # Panics
+    /// Panics if `order` is `Acquire` or `AcqRel`.
+    ///
+    #[inline(always)]
+    pub fn store(&self, ptr: SyncMutPtr<T>, order: Ordering) {
+        self.0.store(ptr.inner(), order);
+    }
+
+    ///
+    /// Stores `ptr` in the atomic cell, returning the previous pointer.
+    ///
+    #[inline(always)]
+    pub fn swap(&self, ptr: SyncMutPtr<T>, order: Ordering) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.swap(ptr.inner(), order))
+    }
+
+    ///
+    /// Stores `new` in the atomic cell if the current pointer is the same as `current`.
+    ///
+    /// # Errors
+    /// Returns the current pointer if it was not the same as `current`.
+    ///
+    /// # Panics
+    /// Panics if `failure` is `Release` or `AcqRel`.
+    ///
+    #[inline(always)]
+    pub fn compare_exchange(
+        &self,
+        current: SyncMutPtr<T>,
+        new: SyncMutPtr<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<SyncMutPtr<T>, SyncMutPtr<T>> {
+        self.0
+            .compare_exchange(current.inner(), new.inner(), success, failure)
+            .map(|ptr| SyncMutPtr::wrap(ptr))
+            .map_err(|ptr| SyncMutPtr::wrap(ptr))
+    }
+
+    ///
+    /// Stores `new` in the atomic cell if the current pointer is the same as `current`.
+    ///
+    /// Unlike `compare_exchange` this is allowed to spuriously fail even when the comparison succeeds.
+    ///
+    /// # Errors
+    /// Returns the current pointer if it was not the same as `current` or if the exchange failed spuriously.
+    ///
+    /// # Panics
+    /// Panics if `failure` is `Release` or `AcqRel`.
+    ///
+    #[inline(always)]
+    pub fn compare_exchange_weak(
+        &self,
+        current: SyncMutPtr<T>,
+        new: SyncMutPtr<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<SyncMutPtr<T>, SyncMutPtr<T>> {
+        self.0
+            .compare_exchange_weak(current.inner(), new.inner(), success, failure)
+            .map(|ptr| SyncMutPtr::wrap(ptr))
+            .map_err(|ptr| SyncMutPtr::wrap(ptr))
+    }
+
+    ///
+    /// Fetches the pointer and applies `f` to it, storing the returned pointer if `f` returns `Some`.
+    ///
+    /// # Errors
+    /// Returns the last fetched pointer if `f` returned `None`.
+    ///
+    /// # Panics
+    /// Panics if `fetch_order` is `Release` or `AcqRel`.
+    ///
+    #[inline(always)]
+    pub fn fetch_update<F: FnMut(SyncMutPtr<T>) -> Option<SyncMutPtr<T>>>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<SyncMutPtr<T>, SyncMutPtr<T>> {
+        self.0
+            .fetch_update(set_order, fetch_order, |ptr| {
+                f(SyncMutPtr::wrap(ptr)).map(|ptr| ptr.inner())
+            })
+            .map(|ptr| SyncMutPtr::wrap(ptr))
+            .map_err(|ptr| SyncMutPtr::wrap(ptr))
+    }
+
+    ///
+    /// Offsets the contained pointer by `count` elements of `T`, returning the previous pointer.
+    ///
+    /// This uses wrapping arithmetic and does not require the result to be in bounds.
+    ///
+    #[inline(always)]
+    pub fn fetch_ptr_add(&self, count: usize, order: Ordering) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.fetch_ptr_add(count, order))
+    }
+
+    ///
+    /// Offsets the contained pointer backwards by `count` elements of `T`, returning the previous pointer.
+    ///
+    /// This uses wrapping arithmetic and does not require the result to be in bounds.
+    ///
+    #[inline(always)]
+    pub fn fetch_ptr_sub(&self, count: usize, order: Ordering) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.fetch_ptr_sub(count, order))
+    }
+
+    ///
+    /// Offsets the contained pointer by `count` bytes, returning the previous pointer.
+    ///
+    /// This uses wrapping arithmetic and does not require the result to be in bounds.
+    ///
+    #[inline(always)]
+    pub fn fetch_byte_add(&self, count: usize, order: Ordering) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.fetch_byte_add(count, order))
+    }
+
+    ///
+    /// Offsets the contained pointer backwards by `count` bytes, returning the previous pointer.
+    ///
+    /// This uses wrapping arithmetic and does not require the result to be in bounds.
+    ///
+    #[inline(always)]
+    pub fn fetch_byte_sub(&self, count: usize, order: Ordering) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.fetch_byte_sub(count, order))
+    }
+}
+
+impl<T> Default for SyncAtomicPtr<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> core::fmt::Debug for SyncAtomicPtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SyncAtomicPtr").field(&self.0).finish()
+    }
+}
+
+impl<T> From<SyncMutPtr<T>> for SyncAtomicPtr<T> {
+    #[inline(always)]
+    fn from(val: SyncMutPtr<T>) -> Self {
+        Self::new(val)
+    }
+}
+
+impl<T> From<SyncAtomicPtr<T>> for AtomicPtr<T> {
+    #[inline(always)]
+    fn from(val: SyncAtomicPtr<T>) -> Self {
+        val.into_atomic()
+    }
+}
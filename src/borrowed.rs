@@ -0,0 +1,197 @@
+//! Wrappers that keep the lifetime of the Rust borrow they were made from.
+//!
+//! A `SyncConstPtr` made from `&x` forgets how long `x` lives. [`SyncRef`] and [`SendMut`]
+//! remember it, so the compiler rejects any use after the borrow has ended. This makes it
+//! safe to hand a pointer into the parent's stack to a scoped thread:
+//!
+//! ```
+//! use sync_ptr::SendMut;
+//!
+//! let mut total = 0u64;
+//! let mut out = SendMut::new(&mut total);
+//! std::thread::scope(|s| {
+//!     s.spawn(move || *out.get_mut() += 5);
+//! });
+//! assert_eq!(total, 5);
+//! ```
+//!
+//! The wrapper cannot escape the borrow:
+//!
+//! ```compile_fail
+//! use sync_ptr::SyncRef;
+//!
+//! let ptr = {
+//!     let value = 1u32;
+//!     SyncRef::new(&value)
+//! };
+//! let _ = ptr.get();
+//! ```
+
+use crate::{SendMutPtr, SyncConstPtr};
+use core::fmt::{Formatter, Pointer};
+use core::marker::PhantomData;
+
+///
+/// Wrapped const raw pointer that is Send+Sync and borrows its pointee for `'a`.
+///
+/// Behaves like `&'a T` for `T: Sync`, but can be turned into a `SyncConstPtr` for FFI calls.
+///
+#[repr(transparent)]
+pub struct SyncRef<'a, T: ?Sized>(SyncConstPtr<T>, PhantomData<&'a T>);
+
+impl<'a, T: ?Sized + Sync> SyncRef<'a, T> {
+    ///
+    /// Makes a Send+Sync pointer to the value behind `val` that cannot outlive the borrow.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(val: &'a T) -> Self {
+        Self(SyncConstPtr::from_ref(val), PhantomData)
+    }
+}
+
+impl<'a, T: ?Sized> SyncRef<'a, T> {
+    ///
+    /// Returns the reference the pointer was made from.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(&self) -> &'a T {
+        // SAFETY: the pointer was made from a `&'a T`.
+        unsafe { &*self.0.inner() }
+    }
+
+    ///
+    /// Returns the wrapped pointer, which no longer carries the lifetime.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn ptr(&self) -> SyncConstPtr<T> {
+        self.0
+    }
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send or Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *const T {
+        self.0.inner()
+    }
+}
+
+impl<T: ?Sized> Clone for SyncRef<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for SyncRef<'_, T> {}
+
+impl<T: ?Sized> Pointer for SyncRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for SyncRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SyncRef").field(&self.0.inner()).finish()
+    }
+}
+
+impl<'a, T: ?Sized + Sync> From<&'a T> for SyncRef<'a, T> {
+    #[inline(always)]
+    fn from(val: &'a T) -> Self {
+        Self::new(val)
+    }
+}
+
+///
+/// Wrapped mutable raw pointer that is Send but not Sync and borrows its pointee for `'a`.
+///
+/// Behaves like `&'a mut T` for `T: Send`, so unlike the other wrappers it is not `Copy`.
+///
+#[repr(transparent)]
+pub struct SendMut<'a, T: ?Sized>(SendMutPtr<T>, PhantomData<&'a mut T>);
+
+impl<'a, T: ?Sized + Send> SendMut<'a, T> {
+    ///
+    /// Makes a Send pointer to the value behind `val` that cannot outlive the borrow.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(val: &'a mut T) -> Self {
+        Self(SendMutPtr::from_mut(val), PhantomData)
+    }
+}
+
+impl<'a, T: ?Sized> SendMut<'a, T> {
+    ///
+    /// Returns a shared reference to the pointee.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        // SAFETY: the pointer was made from a `&'a mut T` that `self` still holds.
+        unsafe { &*self.0.inner() }
+    }
+
+    ///
+    /// Returns a mutable reference to the pointee.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get_mut(&mut self) -> &mut T {
+        // SAFETY: the pointer was made from a `&'a mut T` that `self` still holds.
+        unsafe { &mut *self.0.inner() }
+    }
+
+    ///
+    /// Returns the mutable reference the pointer was made from.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_mut(self) -> &'a mut T {
+        // SAFETY: the pointer was made from a `&'a mut T` and `self` is consumed.
+        unsafe { &mut *self.0.inner() }
+    }
+
+    ///
+    /// Returns the wrapped pointer, which no longer carries the lifetime.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn ptr(&self) -> SendMutPtr<T> {
+        self.0
+    }
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send or Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *mut T {
+        self.0.inner()
+    }
+}
+
+impl<T: ?Sized> Pointer for SendMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for SendMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SendMut").field(&self.0.inner()).finish()
+    }
+}
+
+impl<'a, T: ?Sized + Send> From<&'a mut T> for SendMut<'a, T> {
+    #[inline(always)]
+    fn from(val: &'a mut T) -> Self {
+        Self::new(val)
+    }
+}
@@ -0,0 +1,107 @@
+//! Zero-copy conversions between collections of raw pointers and collections of wrappers.
+//!
+//! Every wrapper is `#[repr(transparent)]` over its raw pointer, so a slice, array or `Vec`
+//! of raw pointers can be reinterpreted in place instead of mapped into a new allocation:
+//!
+//! ```
+//! use sync_ptr::SyncMutPtr;
+//!
+//! let mut values = [3u32, 1, 2];
+//! let raw: Vec<*mut u32> = values.iter_mut().map(|v| v as *mut u32).collect();
+//!
+//! let mut wrapped = unsafe { SyncMutPtr::from_raw_vec(raw) };
+//! let wrapped = std::thread::spawn(move || {
+//!     wrapped.sort_by_key(|p| unsafe { p.read() });
+//!     wrapped
+//! })
+//! .join()
+//! .unwrap();
+//!
+//! let raw = SyncMutPtr::into_raw_vec(wrapped);
+//! assert_eq!(unsafe { *raw[0] }, 1);
+//! ```
+
+use crate::marker::{DerefMode, Mutability, ThreadSafety};
+use crate::Ptr;
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode> Ptr<T, M, S, D> {
+    ///
+    /// Reinterprets a slice of raw pointers as a slice of wrappers without copying.
+    ///
+    /// # Safety
+    /// Every pointer in `slice` must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped pointers to not use them
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw_slice(slice: &[M::Raw<T>]) -> &[Self] {
+        // SAFETY: `Ptr` is repr(transparent) over `*const T`, which has the same layout as `M::Raw<T>`.
+        &*(core::ptr::from_ref(slice) as *const [Self])
+    }
+
+    ///
+    /// Reinterprets a mutable slice of raw pointers as a mutable slice of wrappers without copying.
+    ///
+    /// # Safety
+    /// Same as `from_raw_slice`, which also applies to every pointer stored into the returned slice
+    /// for as long as `slice` is used afterward.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw_slice_mut(slice: &mut [M::Raw<T>]) -> &mut [Self] {
+        // SAFETY: `Ptr` is repr(transparent) over `*const T`, which has the same layout as `M::Raw<T>`.
+        &mut *(core::ptr::from_mut(slice) as *mut [Self])
+    }
+
+    ///
+    /// Reinterprets an array of raw pointers as an array of wrappers without copying.
+    ///
+    /// # Safety
+    /// Same as `from_raw_slice`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw_array<const N: usize>(array: [M::Raw<T>; N]) -> [Self; N] {
+        // SAFETY: same layout as in `from_raw_slice`, and raw pointers have no destructor.
+        core::mem::transmute_copy(&array)
+    }
+
+    ///
+    /// Reinterprets a `Vec` of raw pointers as a `Vec` of wrappers without copying or reallocating.
+    ///
+    /// # Safety
+    /// Same as `from_raw_slice`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn from_raw_vec(vec: Vec<M::Raw<T>>) -> Vec<Self> {
+        let mut vec = ManuallyDrop::new(vec);
+        // SAFETY: same layout as in `from_raw_slice`, so the allocation can be reused as is.
+        Vec::from_raw_parts(vec.as_mut_ptr().cast::<Self>(), vec.len(), vec.capacity())
+    }
+
+    ///
+    /// Reinterprets a slice of wrappers as a slice of raw pointers without copying.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_raw_slice(slice: &[Self]) -> &[M::Raw<T>] {
+        // SAFETY: `Ptr` is repr(transparent) over `*const T`, which has the same layout as `M::Raw<T>`.
+        unsafe { &*(core::ptr::from_ref(slice) as *const [M::Raw<T>]) }
+    }
+
+    ///
+    /// Reinterprets a `Vec` of wrappers as a `Vec` of raw pointers without copying or reallocating.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn into_raw_vec(vec: Vec<Self>) -> Vec<M::Raw<T>> {
+        let mut vec = ManuallyDrop::new(vec);
+        // SAFETY: same layout as in `as_raw_slice`, so the allocation can be reused as is.
+        unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+    }
+}
@@ -0,0 +1,76 @@
+//! Error type of the validating constructors.
+
+use core::fmt::{Display, Formatter};
+use core::ops::Range;
+
+///
+/// Reason why a pointer was rejected by one of the validating constructors like `try_new`.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PtrError {
+    /// The pointer is null.
+    Null,
+    /// The address of the pointer is not a multiple of the required alignment.
+    Misaligned {
+        /// Address of the rejected pointer.
+        addr: usize,
+        /// Required alignment in bytes.
+        align: usize,
+    },
+    /// The pointee does not lie completely within the allowed address range.
+    OutOfRange {
+        /// Address of the rejected pointer.
+        addr: usize,
+        /// Size of the pointee in bytes.
+        size: usize,
+        /// The allowed address range.
+        range: Range<usize>,
+    },
+}
+
+impl PtrError {
+    ///
+    /// Checks that a pointer to `T` at `addr` is not null, aligned for `T`
+    /// and, if `range` is given, that the pointee lies completely within `range`.
+    ///
+    pub(crate) fn check<T>(addr: usize, range: Option<Range<usize>>) -> Result<(), Self> {
+        if addr == 0 {
+            return Err(Self::Null);
+        }
+
+        let align = align_of::<T>();
+        if addr & (align - 1) != 0 {
+            return Err(Self::Misaligned { addr, align });
+        }
+
+        if let Some(range) = range {
+            let size = size_of::<T>();
+            let in_range =
+                addr >= range.start && addr.checked_add(size).is_some_and(|end| end <= range.end);
+            if !in_range {
+                return Err(Self::OutOfRange { addr, size, range });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for PtrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => f.write_str("pointer is null"),
+            Self::Misaligned { addr, align } => {
+                write!(f, "pointer {addr:#x} is not aligned to {align} bytes")
+            }
+            Self::OutOfRange { addr, size, range } => write!(
+                f,
+                "pointer {addr:#x} to {size} bytes is outside of the range {:#x}..{:#x}",
+                range.start, range.end
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PtrError {}
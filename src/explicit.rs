@@ -0,0 +1,38 @@
+//! Wrappers that do not implement `Deref` to the raw pointer.
+//!
+//! With `Deref` any raw pointer method can be called on a wrapper, and the result silently
+//! loses Send and Sync. The aliases in this module have the same inherent methods as the
+//! aliases of the same name in the crate root, but raw pointer methods that are not
+//! wrapped are not reachable, the raw pointer has to be taken out explicitly with `inner`.
+//!
+//! ```compile_fail
+//! use sync_ptr::explicit::SyncMutPtr;
+//!
+//! let ptr = SyncMutPtr::<u32>::null();
+//! let _ = ptr.cast_const(); // No `Deref` to `*mut u32`.
+//! ```
+//!
+//! Convert between the two with `into_explicit` and `into_auto_deref`.
+
+use crate::marker::{Const, Mut, NoDeref, SendOnly, SendSync};
+use crate::Ptr;
+
+///
+/// Wrapped mutable raw pointer that is Send+Sync and does not implement `Deref`
+///
+pub type SyncMutPtr<T, Tag = ()> = Ptr<T, Mut, SendSync, NoDeref, Tag>;
+
+///
+/// Wrapped const raw pointer that is Send+Sync and does not implement `Deref`
+///
+pub type SyncConstPtr<T, Tag = ()> = Ptr<T, Const, SendSync, NoDeref, Tag>;
+
+///
+/// Wrapped mutable raw pointer that is Send but not Sync and does not implement `Deref`
+///
+pub type SendMutPtr<T, Tag = ()> = Ptr<T, Mut, SendOnly, NoDeref, Tag>;
+
+///
+/// Wrapped const raw pointer that is Send but not Sync and does not implement `Deref`
+///
+pub type SendConstPtr<T, Tag = ()> = Ptr<T, Const, SendOnly, NoDeref, Tag>;
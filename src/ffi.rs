@@ -0,0 +1,203 @@
+//! Opt-in marker traits for pointee types that live behind an FFI boundary.
+//!
+//! Implementing [`ExternSync`] or [`ExternSend`] once for an opaque C type moves the safety
+//! argument from every `new` call into a single audited impl:
+//!
+//! ```
+//! use sync_ptr::*;
+//!
+//! #[repr(C)]
+//! struct Context {
+//!     _private: [u8; 0],
+//! }
+//!
+//! // SAFETY: the C library documents its context as thread safe.
+//! unsafe impl ExternSync for Context {}
+//!
+//! let handle: *mut Context = core::ptr::null_mut();
+//! let ptr: SyncMutPtr<Context> = handle.into_sync_mut();
+//! assert_eq!(ptr, SyncMutPtr::from_extern(handle));
+//! ```
+
+use crate::marker::{Const, DerefMode, Mut, SendOnly, SendSync};
+use crate::{Ptr, SendConstPtr, SendMutPtr, SyncConstPtr, SyncMutPtr};
+
+///
+/// Marks a pointee type whose pointers can be sent to other threads.
+///
+/// This enables the safe `from_extern` constructors of `SendMutPtr` and `SendConstPtr`
+/// and the `into_send_*` methods on raw pointers to `Self`.
+///
+/// # Safety
+/// Every pointer to `Self` must be able to handle being sent to other threads
+/// and being used there.
+///
+pub unsafe trait ExternSend {}
+
+///
+/// Marks a pointee type whose pointers can be used in other threads concurrently.
+///
+/// This enables the safe `from_extern` constructors of all four wrappers
+/// and the `into_sync_*` methods on raw pointers to `Self`.
+///
+/// Every `ExternSync` type is also `ExternSend` through a blanket impl,
+/// so only one of the two traits is implemented for a type.
+///
+/// # Safety
+/// Every pointer to `Self` must be able to handle being sent and used in other threads concurrently.
+///
+pub unsafe trait ExternSync: ExternSend {}
+
+// SAFETY: `ExternSync` requires that pointers can be sent and used in other threads, which
+// includes everything `ExternSend` requires.
+unsafe impl<T: ?Sized + ExternSync> ExternSend for T {}
+
+impl<T: ?Sized + ExternSync, D: DerefMode> Ptr<T, Mut, SendSync, D> {
+    ///
+    /// Makes `ptr` Send+Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_extern(ptr: *mut T) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl<T: ?Sized + ExternSync, D: DerefMode> Ptr<T, Const, SendSync, D> {
+    ///
+    /// Makes `ptr` Send+Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_extern(ptr: *const T) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl<T: ?Sized + ExternSend, D: DerefMode> Ptr<T, Mut, SendOnly, D> {
+    ///
+    /// Makes `ptr` Send.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_extern(ptr: *mut T) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl<T: ?Sized + ExternSend, D: DerefMode> Ptr<T, Const, SendOnly, D> {
+    ///
+    /// Makes `ptr` Send.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_extern(ptr: *const T) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+///
+/// Safe counterparts of `FromConstPtr` for raw pointers to `ExternSend` and `ExternSync` types.
+///
+pub trait FromExternConstPtr<T: ?Sized> {
+    ///
+    /// Makes `self` immutable and Send+Sync
+    ///
+    #[must_use]
+    fn into_sync_const(self) -> SyncConstPtr<T>
+    where
+        T: ExternSync;
+
+    ///
+    /// Makes `self` immutable and Send
+    ///
+    #[must_use]
+    fn into_send_const(self) -> SendConstPtr<T>
+    where
+        T: ExternSend;
+}
+
+///
+/// Safe counterparts of `FromMutPtr` for raw pointers to `ExternSend` and `ExternSync` types.
+///
+/// A pointee that is only `ExternSend` cannot be made Sync this way:
+/// ```compile_fail
+/// use sync_ptr::*;
+///
+/// struct Handle;
+/// unsafe impl ExternSend for Handle {}
+///
+/// let _ = core::ptr::null_mut::<Handle>().into_sync_mut();
+/// ```
+///
+pub trait FromExternMutPtr<T: ?Sized>: FromExternConstPtr<T> {
+    ///
+    /// Makes `self` Send+Sync
+    ///
+    #[must_use]
+    fn into_sync_mut(self) -> SyncMutPtr<T>
+    where
+        T: ExternSync;
+
+    ///
+    /// Makes `self` Send
+    ///
+    #[must_use]
+    fn into_send_mut(self) -> SendMutPtr<T>
+    where
+        T: ExternSend;
+}
+
+impl<T: ?Sized> FromExternConstPtr<T> for *const T {
+    #[inline(always)]
+    fn into_sync_const(self) -> SyncConstPtr<T>
+    where
+        T: ExternSync,
+    {
+        SyncConstPtr::wrap(self)
+    }
+
+    #[inline(always)]
+    fn into_send_const(self) -> SendConstPtr<T>
+    where
+        T: ExternSend,
+    {
+        SendConstPtr::wrap(self)
+    }
+}
+
+impl<T: ?Sized> FromExternConstPtr<T> for *mut T {
+    #[inline(always)]
+    fn into_sync_const(self) -> SyncConstPtr<T>
+    where
+        T: ExternSync,
+    {
+        SyncConstPtr::wrap(self)
+    }
+
+    #[inline(always)]
+    fn into_send_const(self) -> SendConstPtr<T>
+    where
+        T: ExternSend,
+    {
+        SendConstPtr::wrap(self)
+    }
+}
+
+impl<T: ?Sized> FromExternMutPtr<T> for *mut T {
+    #[inline(always)]
+    fn into_sync_mut(self) -> SyncMutPtr<T>
+    where
+        T: ExternSync,
+    {
+        SyncMutPtr::wrap(self)
+    }
+
+    #[inline(always)]
+    fn into_send_mut(self) -> SendMutPtr<T>
+    where
+        T: ExternSend,
+    {
+        SendMutPtr::wrap(self)
+    }
+}
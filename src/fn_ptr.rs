@@ -0,0 +1,211 @@
+//! Send+Sync wrapper for function pointers, for example callbacks from a C library.
+//!
+//! Rust function pointers are already Send and Sync, but a C function may not be safe to call
+//! from other threads. [`SyncFnPtr`] makes that assertion explicit, like the data wrappers do
+//! for data pointers:
+//!
+//! ```
+//! use sync_ptr::SyncFnPtr;
+//!
+//! extern "C" fn double(value: i32) -> i32 {
+//!     value * 2
+//! }
+//!
+//! let callback = unsafe { SyncFnPtr::new(double as extern "C" fn(i32) -> i32) };
+//! let result = std::thread::spawn(move || callback.get()(21)).join().unwrap();
+//! assert_eq!(result, 42);
+//! ```
+
+use core::ffi::c_void;
+use core::fmt::{Formatter, Pointer};
+
+/// Prevents implementations of `FnPtr` outside of this crate.
+mod sealed {
+    /// Implemented by every function pointer type that implements `FnPtr`.
+    pub trait Sealed {}
+}
+
+///
+/// Function pointer types that `SyncFnPtr` can wrap.
+///
+/// Implemented for safe and unsafe `fn`, `extern "C" fn`, `extern "system" fn` and their
+/// `"C-unwind"` and `"system-unwind"` variants with up to 8 arguments.
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// Higher-ranked signatures such as `for<'a> fn(&'a T)` are not covered, each lifetime binder
+/// would need its own impl. Cast such a function to a signature with a concrete lifetime
+/// or with raw pointer arguments before wrapping it.
+///
+pub trait FnPtr: sealed::Sealed + Copy {
+    ///
+    /// Returns the address of the function.
+    ///
+    #[must_use]
+    fn addr(self) -> *const c_void;
+
+    ///
+    /// Makes a function pointer from the address of a function.
+    ///
+    /// # Safety
+    /// `addr` must not be null and must point to a function with this signature.
+    ///
+    #[must_use]
+    unsafe fn from_addr(addr: *const c_void) -> Self;
+}
+
+/// Implements `FnPtr` for the function pointer types with the arguments `$A`.
+macro_rules! fn_ptr_impl {
+    ($($A:ident),*) => {
+        fn_ptr_impl!(@abi [fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [unsafe fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [extern "C" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [unsafe extern "C" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [extern "C-unwind" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [unsafe extern "C-unwind" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [extern "system" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [unsafe extern "system" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [extern "system-unwind" fn($($A),*) -> R] $($A),*);
+        fn_ptr_impl!(@abi [unsafe extern "system-unwind" fn($($A),*) -> R] $($A),*);
+    };
+    (@abi [$F:ty] $($A:ident),*) => {
+        impl<R, $($A),*> sealed::Sealed for $F {}
+
+        impl<R, $($A),*> FnPtr for $F {
+            #[inline(always)]
+            fn addr(self) -> *const c_void {
+                self as *const c_void
+            }
+
+            #[inline(always)]
+            unsafe fn from_addr(addr: *const c_void) -> Self {
+                core::mem::transmute::<*const c_void, Self>(addr)
+            }
+        }
+    };
+}
+
+fn_ptr_impl!();
+fn_ptr_impl!(A1);
+fn_ptr_impl!(A1, A2);
+fn_ptr_impl!(A1, A2, A3);
+fn_ptr_impl!(A1, A2, A3, A4);
+fn_ptr_impl!(A1, A2, A3, A4, A5);
+fn_ptr_impl!(A1, A2, A3, A4, A5, A6);
+fn_ptr_impl!(A1, A2, A3, A4, A5, A6, A7);
+fn_ptr_impl!(A1, A2, A3, A4, A5, A6, A7, A8);
+
+///
+/// Wrapped function pointer that is Send+Sync
+///
+/// `F` is a function pointer type such as `extern "C" fn(*mut c_void) -> i32`,
+/// see `FnPtr` for the supported signatures.
+///
+// `F` is always `Copy`, so unlike for the data wrappers `Clone` and `Copy` can be derived.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct SyncFnPtr<F: FnPtr>(F);
+
+unsafe impl<F: FnPtr> Sync for SyncFnPtr<F> {}
+unsafe impl<F: FnPtr> Send for SyncFnPtr<F> {}
+
+impl<F: FnPtr> SyncFnPtr<F> {
+    ///
+    /// Makes `f` Send+Sync
+    ///
+    /// # Safety
+    /// The function must be safe to call from other threads, also concurrently,
+    /// or special care must be taken when using the wrapped `f` to not call it
+    /// in any other thread.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new(f: F) -> Self {
+        Self(f)
+    }
+
+    ///
+    /// Makes a Send+Sync function pointer from a symbol address, or returns `None` if `addr` is null.
+    ///
+    /// This is meant for the result of `dlsym`, `GetProcAddress` and similar functions.
+    ///
+    /// # Safety
+    /// `addr` must be null or point to a function with the signature `F`,
+    /// and the function must be safe to call from other threads like for `new`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn from_symbol(addr: *const c_void) -> Option<Self> {
+        if addr.is_null() {
+            return None;
+        }
+
+        Some(Self(F::from_addr(addr)))
+    }
+
+    ///
+    /// Returns inner function pointer, calling it is then no longer covered by the assertion of `new`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(&self) -> F {
+        self.0
+    }
+
+    ///
+    /// Returns the address of the function.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn addr(&self) -> *const c_void {
+        self.0.addr()
+    }
+
+    ///
+    /// Casts the function pointer to another signature.
+    ///
+    /// # Safety
+    /// Calling the function through the signature `G` must be valid for the function.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn cast<G: FnPtr>(&self) -> SyncFnPtr<G> {
+        SyncFnPtr(G::from_addr(self.addr()))
+    }
+}
+
+impl<F: FnPtr> Pointer for SyncFnPtr<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.addr(), f)
+    }
+}
+
+impl<F: FnPtr> Eq for SyncFnPtr<F> {}
+impl<F: FnPtr> PartialEq for SyncFnPtr<F> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&self.addr(), &other.addr())
+    }
+}
+
+impl<F: FnPtr> PartialOrd for SyncFnPtr<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: FnPtr> Ord for SyncFnPtr<F> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(&self.addr(), &other.addr())
+    }
+}
+
+impl<F: FnPtr> core::fmt::Debug for SyncFnPtr<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SyncFnPtr").field(&self.addr()).finish()
+    }
+}
+
+impl<F: FnPtr> core::hash::Hash for SyncFnPtr<F> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.addr(), state);
+    }
+}
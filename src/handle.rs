@@ -0,0 +1,185 @@
+//! Macro that generates named Send/Sync handle types for FFI pointers.
+
+///
+/// Generates `#[repr(transparent)]` newtypes around `SyncMutPtr` or `SendMutPtr`
+/// so every kind of C handle becomes its own Rust type.
+///
+/// Each handle is declared as `struct Name: Sync` or `struct Name: Send`, optionally followed by
+/// the pointee type in parentheses (`c_void` if omitted) and `, drop = path` naming a function
+/// that releases the handle. The function is called with the raw pointer when a non-null handle
+/// is dropped. Handles without a drop function are `Copy`.
+///
+/// ```
+/// use sync_ptr::sync_handle;
+///
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct Stream {
+///         _private: [u8; 0],
+///     }
+///
+///     pub unsafe extern "C" fn stream_free(_stream: *mut Stream) {}
+/// }
+///
+/// sync_handle! {
+///     /// Thread safe library context.
+///     pub struct Context: Sync;
+///
+///     /// Stream that can be moved to another thread, but not shared.
+///     pub struct Stream: Send (ffi::Stream), drop = ffi::stream_free;
+/// }
+///
+/// let context = Context::null();
+/// assert!(context.is_null());
+///
+/// let stream = unsafe { Stream::from_raw(core::ptr::null_mut()) };
+/// let raw: *mut ffi::Stream = stream.into_raw();
+/// assert!(raw.is_null());
+/// ```
+///
+#[macro_export]
+macro_rules! sync_handle {
+    (@pointee) => {
+        ::core::ffi::c_void
+    };
+    (@pointee $pointee:ty) => {
+        $pointee
+    };
+    (@wrapper Sync, $pointee:ty) => {
+        $crate::SyncMutPtr<$pointee>
+    };
+    (@wrapper Send, $pointee:ty) => {
+        $crate::SendMutPtr<$pointee>
+    };
+    (@drop $name:ident, $pointee:ty,) => {
+        impl $name {
+            /// Returns the raw pointer of the handle.
+            #[inline(always)]
+            #[must_use]
+            pub const fn into_raw(self) -> *mut $pointee {
+                self.0.inner()
+            }
+        }
+
+        impl ::core::clone::Clone for $name {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl ::core::marker::Copy for $name {}
+    };
+    (@drop $name:ident, $pointee:ty, $drop:path) => {
+        impl $name {
+            /// Returns the raw pointer of the handle without calling the drop function.
+            #[inline(always)]
+            #[must_use = "losing the pointer will leak the handle"]
+            pub fn into_raw(self) -> *mut $pointee {
+                let ptr = self.0.inner();
+                ::core::mem::forget(self);
+                ptr
+            }
+        }
+
+        impl ::core::ops::Drop for $name {
+            fn drop(&mut self) {
+                if !self.0.is_null() {
+                    #[allow(unused_unsafe)]
+                    unsafe {
+                        $drop(self.0.inner());
+                    }
+                }
+            }
+        }
+    };
+    (@handle [$($meta:tt)*] $vis:vis $name:ident, $wrapper:ty, $pointee:ty, [$($drop:path)?]) => {
+        $($meta)*
+        #[repr(transparent)]
+        $vis struct $name($wrapper);
+
+        impl $name {
+            /// Wraps `ptr` in the handle.
+            ///
+            /// # Safety
+            /// `ptr` must be able to handle being sent to other threads, and for Sync handles
+            /// being used in other threads concurrently. If the handle has a drop function,
+            /// `ptr` must be valid to pass to it, and ownership of `ptr` moves to the handle.
+            #[inline(always)]
+            #[must_use]
+            pub const unsafe fn from_raw(ptr: *mut $pointee) -> Self {
+                Self(<$wrapper>::new(ptr))
+            }
+
+            /// Makes a null handle.
+            #[inline(always)]
+            #[must_use]
+            pub const fn null() -> Self {
+                Self(<$wrapper>::null())
+            }
+
+            /// Returns true if the handle is null.
+            #[inline(always)]
+            #[must_use]
+            pub const fn is_null(&self) -> bool {
+                self.0.is_null()
+            }
+
+            /// Returns the raw pointer of the handle, which is then no longer Send or Sync.
+            #[inline(always)]
+            #[must_use]
+            pub const fn as_raw(&self) -> *mut $pointee {
+                self.0.inner()
+            }
+
+            /// Returns the wrapped pointer of the handle.
+            ///
+            /// For handles with a drop function the handle keeps ownership.
+            #[inline(always)]
+            #[must_use]
+            pub const fn as_ptr(&self) -> $wrapper {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(::core::stringify!($name))
+                    .field(&self.0.inner())
+                    .finish()
+            }
+        }
+
+        impl ::core::fmt::Pointer for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Pointer::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl ::core::cmp::Eq for $name {}
+
+        impl ::core::hash::Hash for $name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.0, state);
+            }
+        }
+
+        $crate::sync_handle!(@drop $name, $pointee, $($drop)?);
+    };
+    ($($(#[$meta:meta])* $vis:vis struct $name:ident: $level:ident $(($pointee:ty))? $(, drop = $drop:path)?;)*) => {
+        $(
+            $crate::sync_handle!(@handle
+                [$(#[$meta])*] $vis $name,
+                $crate::sync_handle!(@wrapper $level, $crate::sync_handle!(@pointee $($pointee)?)),
+                $crate::sync_handle!(@pointee $($pointee)?),
+                [$($drop)?]
+            );
+        )*
+    };
+}
@@ -4,6 +4,18 @@
 //! then you should be able to call `my_ptr.as_sync_const()` among others on any raw pointer
 //! to obtain a wrapped version of your raw pointer that is Sync/Send.
 //!
+//! Pointers to unsized data such as `*mut [u8]`, `*const str` or `*mut dyn Trait` can be wrapped too,
+//! the slice length or vtable is kept.
+//!
+//! All four wrappers are aliases of the single generic type `Ptr<T, M, S>`,
+//! where the markers in [`marker`] select the mutability and the thread safety.
+//! The wrappers in [`explicit`] do not implement `Deref` to the raw pointer,
+//! for code that wants every pointer operation to keep its Send/Sync wrapper.
+//! [`SyncRef`] and [`SendMut`] keep the lifetime of the Rust borrow they were made from.
+//! The non-null wrappers such as [`SyncMutNonNull`] are separate types and not part of `Ptr`,
+//! so tags, `NoDeref`, `TryFrom`, the bulk conversions and the comparisons between wrapper types
+//! are only available on the nullable wrappers.
+//!
 #![no_std]
 #![deny(clippy::correctness)]
 #![warn(
@@ -24,8 +36,14 @@
 #![allow(clippy::inline_always)]
 extern crate alloc;
 
+use alloc::boxed::Box;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
 use core::fmt::{Formatter, Pointer};
-use core::ops::Deref;
+use core::marker::PhantomData;
+use core::ops::{Deref, Range};
+use core::ptr::NonNull;
 
 /// Implement common traits for type `SelfType` by forwarding implementation
 /// to underlying pointer.
@@ -45,46 +63,49 @@ use core::ops::Deref;
 /// traits on primitive pointers, we have to manually implement them.
 macro_rules! trait_impl {
     ($SelfType:ident) => {
-        impl<T> Clone for $SelfType<T> {
+        impl<T: ?Sized> Clone for $SelfType<T> {
             #[inline(always)]
             fn clone(&self) -> Self {
                 *self
             }
         }
 
-        impl<T> Copy for $SelfType<T> {}
-        impl<T> Pointer for $SelfType<T> {
+        impl<T: ?Sized> Copy for $SelfType<T> {}
+        impl<T: ?Sized> Pointer for $SelfType<T> {
             fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                 core::fmt::Pointer::fmt(&self.0, f)
             }
         }
 
-        impl<T> Eq for $SelfType<T> {}
-        impl<T> PartialEq for $SelfType<T> {
+        impl<T: ?Sized> Eq for $SelfType<T> {}
+        impl<T: ?Sized> PartialEq for $SelfType<T> {
+            // Same as for primitive pointers, metadata (slice length, vtable) is compared too.
+            #[allow(ambiguous_wide_pointer_comparisons)]
             fn eq(&self, other: &Self) -> bool {
                 PartialEq::eq(&self.0, &other.0)
             }
         }
 
-        impl<T> PartialOrd for $SelfType<T> {
+        impl<T: ?Sized> PartialOrd for $SelfType<T> {
             fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
                 Some(self.cmp(other))
             }
         }
 
-        impl<T> Ord for $SelfType<T> {
+        impl<T: ?Sized> Ord for $SelfType<T> {
+            #[allow(ambiguous_wide_pointer_comparisons)]
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                 Ord::cmp(&self.0, &other.0)
             }
         }
 
-        impl<T> core::fmt::Debug for $SelfType<T> {
+        impl<T: ?Sized> core::fmt::Debug for $SelfType<T> {
             fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                 f.debug_tuple(stringify!($SelfType)).field(&self.0).finish()
             }
         }
 
-        impl<T> core::hash::Hash for $SelfType<T> {
+        impl<T: ?Sized> core::hash::Hash for $SelfType<T> {
             fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
                 core::hash::Hash::hash(&self.0, state);
             }
@@ -92,425 +113,1438 @@ macro_rules! trait_impl {
     };
 }
 
+mod aligned;
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
+mod borrowed;
+mod bulk;
+mod error;
+pub mod explicit;
+mod ffi;
+mod fn_ptr;
+mod handle;
+pub mod marker;
+mod non_null;
+mod owned;
+mod slice;
+mod tagged;
+mod traits;
+mod unique;
+
+pub use aligned::*;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::*;
+pub use borrowed::*;
+pub use error::*;
+pub use ffi::*;
+pub use fn_ptr::*;
+pub use non_null::*;
+pub use owned::*;
+pub use slice::*;
+pub use tagged::*;
+pub use traits::*;
+pub use unique::*;
+
+use marker::{
+    AutoDeref, Const, DerefMode, Mut, Mutability, NoDeref, SendOnly, SendSync, ThreadSafety,
+};
+
+/// Phantom type parameters of `Ptr`, `Tag` is behind `fn() -> Tag` so it never affects auto traits.
+/// `M::Raw<T>` makes `Ptr` invariant in `T`, like `*mut T`.
+type Markers<T, M, S, D, Tag> = PhantomData<(<M as Mutability>::Raw<T>, M, S, D, fn() -> Tag)>;
+
 ///
-/// Wrapped mutable raw pointer that is Send+Sync
+/// Wrapped raw pointer to `T` whose mutability is `M` and whose thread safety is `S`.
+///
+/// `M` is `marker::Mut` or `marker::Const` and `S` is `marker::SendSync` or `marker::SendOnly`.
+/// `D` is `marker::AutoDeref` unless the `Deref` to the raw pointer is opted out with `marker::NoDeref`.
+/// `Tag` is any type that only distinguishes otherwise identical pointers, for example
+/// handles of different kinds that are all `*mut c_void`. Pointers with different tags
+/// cannot be mixed up. Constructors make untagged pointers, `retag` changes the tag explicitly
+/// and `cast` keeps it.
+/// Usually this is used through one of the aliases `SyncMutPtr`, `SyncConstPtr`,
+/// `SendMutPtr` and `SendConstPtr`.
+///
+/// The pointer is stored as `*const T` regardless of `M`, but like `*mut T` all four wrappers
+/// are invariant in `T`, so a `SyncMutPtr<&'static str>` cannot become a `SyncMutPtr<&'a str>`
+/// that a shorter-lived reference is written through. The variance cannot depend on `M`,
+/// so the const wrappers are invariant too, unlike `*const T`.
+///
+/// Every conversion that gives up a capability is safe: `into_const` drops mutability,
+/// `into_send` drops Sync and `inner`, `Deref` and `From` drop the wrapper entirely.
+/// Every conversion that gains a capability is unsafe: `into_mut` and `into_sync`,
+/// as well as wrapping a raw pointer in the first place.
+///
+/// Wrapping is safe when the pointee's own auto traits already allow it, with the same bounds
+/// as for references: a `Mut` pointer acts like `&mut T`, so it needs `T: Send` to be Send and
+/// additionally `T: Sync` to be Sync, and a `Const` pointer acts like `&T`, which needs `T: Sync`
+/// to be Send or Sync. `from_mut`, `from_ref`, `from_box` and the `TryFrom` impls use these bounds.
 ///
 #[repr(transparent)]
-pub struct SyncMutPtr<T>(*mut T);
+pub struct Ptr<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode = AutoDeref, Tag = ()>(
+    *const T,
+    Markers<T, M, S, D, Tag>,
+);
+
+unsafe impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Send
+    for Ptr<T, M, S, D, Tag>
+{
+}
+unsafe impl<T: ?Sized, M: Mutability, D: DerefMode, Tag> Sync for Ptr<T, M, SendSync, D, Tag> {}
+
+///
+/// Wrapped mutable raw pointer that is Send+Sync
+///
+/// The pointee type cannot be shortened, like for `*mut T`:
+/// ```compile_fail
+/// # use sync_ptr::SyncMutPtr;
+/// fn shrink<'a>(p: SyncMutPtr<&'static str>) -> SyncMutPtr<&'a str> {
+///     p
+/// }
+/// ```
+///
+pub type SyncMutPtr<T, Tag = ()> = Ptr<T, Mut, SendSync, AutoDeref, Tag>;
+
+///
+/// Wrapped const raw pointer that is Send+Sync
+///
+/// Only the mutable wrappers can be written through:
+/// ```compile_fail
+/// # use sync_ptr::SyncConstPtr;
+/// unsafe { SyncConstPtr::<u32>::dangling().write(1) };
+/// ```
+///
+pub type SyncConstPtr<T, Tag = ()> = Ptr<T, Const, SendSync, AutoDeref, Tag>;
+
+///
+/// Wrapped mutable raw pointer that is Send but not Sync
+///
+pub type SendMutPtr<T, Tag = ()> = Ptr<T, Mut, SendOnly, AutoDeref, Tag>;
+
+///
+/// Wrapped const raw pointer that is Send but not Sync
+///
+pub type SendConstPtr<T, Tag = ()> = Ptr<T, Const, SendOnly, AutoDeref, Tag>;
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Clone for Ptr<T, M, S, D, Tag> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Copy for Ptr<T, M, S, D, Tag> {}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Pointer
+    for Ptr<T, M, S, D, Tag>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Eq for Ptr<T, M, S, D, Tag> {}
+/// Wrappers compare equal to each other regardless of mutability, thread safety and `Deref`,
+/// only the tag has to match.
+impl<
+        T: ?Sized,
+        M: Mutability,
+        S: ThreadSafety,
+        D: DerefMode,
+        M2: Mutability,
+        S2: ThreadSafety,
+        D2: DerefMode,
+        Tag,
+    > PartialEq<Ptr<T, M2, S2, D2, Tag>> for Ptr<T, M, S, D, Tag>
+{
+    // Same as for primitive pointers, metadata (slice length, vtable) is compared too.
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn eq(&self, other: &Ptr<T, M2, S2, D2, Tag>) -> bool {
+        PartialEq::eq(&self.0, &other.0)
+    }
+}
+
+impl<
+        T: ?Sized,
+        M: Mutability,
+        S: ThreadSafety,
+        D: DerefMode,
+        M2: Mutability,
+        S2: ThreadSafety,
+        D2: DerefMode,
+        Tag,
+    > PartialOrd<Ptr<T, M2, S2, D2, Tag>> for Ptr<T, M, S, D, Tag>
+{
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn partial_cmp(&self, other: &Ptr<T, M2, S2, D2, Tag>) -> Option<core::cmp::Ordering> {
+        Some(Ord::cmp(&self.0, &other.0))
+    }
+}
+
+/// Implements `PartialEq` and `PartialOrd` in both directions between `Ptr` and the pointer type `$Raw`,
+/// `$ptr` is the `$Raw` value and `$to_const` turns it into a `*const T`.
+macro_rules! raw_cmp_impl {
+    ($Raw:ty, |$ptr:ident| $to_const:expr) => {
+        impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> PartialEq<$Raw>
+            for Ptr<T, M, S, D, Tag>
+        {
+            #[allow(ambiguous_wide_pointer_comparisons)]
+            fn eq(&self, other: &$Raw) -> bool {
+                let $ptr = *other;
+                PartialEq::eq(&self.0, &$to_const)
+            }
+        }
+
+        impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag>
+            PartialEq<Ptr<T, M, S, D, Tag>> for $Raw
+        {
+            #[inline(always)]
+            fn eq(&self, other: &Ptr<T, M, S, D, Tag>) -> bool {
+                PartialEq::eq(other, self)
+            }
+        }
+
+        impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> PartialOrd<$Raw>
+            for Ptr<T, M, S, D, Tag>
+        {
+            #[allow(ambiguous_wide_pointer_comparisons)]
+            fn partial_cmp(&self, other: &$Raw) -> Option<core::cmp::Ordering> {
+                let $ptr = *other;
+                Some(Ord::cmp(&self.0, &$to_const))
+            }
+        }
+
+        impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag>
+            PartialOrd<Ptr<T, M, S, D, Tag>> for $Raw
+        {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Ptr<T, M, S, D, Tag>) -> Option<core::cmp::Ordering> {
+                PartialOrd::partial_cmp(other, self).map(core::cmp::Ordering::reverse)
+            }
+        }
+    };
+}
+
+raw_cmp_impl!(*const T, |ptr| ptr);
+raw_cmp_impl!(*mut T, |ptr| ptr.cast_const());
+raw_cmp_impl!(NonNull<T>, |ptr| ptr.as_ptr().cast_const());
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Ord for Ptr<T, M, S, D, Tag> {
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(&self.0, &other.0)
+    }
+}
+
+/// Prints the name of the alias, for example `SyncMutPtr(0x1234)`.
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> core::fmt::Debug
+    for Ptr<T, M, S, D, Tag>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(S::NAME)?;
+        f.write_str(M::NAME)?;
+        f.debug_tuple("Ptr").field(&self.0).finish()
+    }
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> core::hash::Hash
+    for Ptr<T, M, S, D, Tag>
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.0, state);
+    }
+}
+
+/// The operators are safe like the wrapping methods, the result is only a pointer
+/// that may be out of bounds, it is never dereferenced.
+impl<T, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> core::ops::Add<usize>
+    for Ptr<T, M, S, D, Tag>
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, count: usize) -> Self {
+        self.wrapping_add(count)
+    }
+}
 
-unsafe impl<T> Sync for SyncMutPtr<T> {}
-unsafe impl<T> Send for SyncMutPtr<T> {}
+impl<T, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> core::ops::Sub<usize>
+    for Ptr<T, M, S, D, Tag>
+{
+    type Output = Self;
 
-trait_impl!(SyncMutPtr);
+    #[inline(always)]
+    fn sub(self, count: usize) -> Self {
+        self.wrapping_sub(count)
+    }
+}
 
-impl<T> SyncMutPtr<T> {
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Ptr<T, M, S, D, Tag> {
     ///
-    /// Makes `ptr` Send+Sync
+    /// Wraps `ptr` without any checks.
     ///
-    /// # Safety
-    /// The `ptr` parameter must be able to handle being sent and used in other threads concurrently,
-    /// or special care must be taken when using the wrapped `ptr` to not use it
-    /// in any way in other threads.
+    /// All constructors and conversions between the wrappers go through this,
+    /// the callers are responsible for upholding the guarantees of `M` and `S`.
     ///
     #[inline(always)]
-    #[must_use]
-    pub const unsafe fn new(ptr: *mut T) -> Self {
-        Self(ptr)
+    pub(crate) const fn wrap(ptr: *const T) -> Self {
+        Self(ptr, PhantomData)
     }
 
     ///
-    /// Makes a Send+Sync null ptr.
+    /// Changes the markers of `ptr` without any checks.
     ///
     #[inline(always)]
-    #[must_use]
-    pub const fn null() -> Self {
-        Self(core::ptr::null_mut())
+    pub(crate) const fn convert<M2: Mutability, S2: ThreadSafety>(&self) -> Ptr<T, M2, S2, D, Tag> {
+        Ptr::wrap(self.0)
     }
 
     ///
-    /// Casts `ptr` to another data type while keeping it Send+Sync.
+    /// Makes `ptr` immutable while keeping its thread safety.
+    ///
+    /// This is a downgrade and therefore safe.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn cast<Y>(&self) -> SyncMutPtr<Y> {
-        SyncMutPtr(self.0.cast())
+    pub const fn into_const(self) -> Ptr<T, Const, S, D, Tag> {
+        self.convert()
     }
 
     ///
-    /// Returns inner `ptr` which is then no longer Send+Sync.
+    /// Makes `ptr` mutable while keeping its thread safety.
+    ///
+    /// # Safety
+    /// Writing through the returned pointer to data that must not be mutated, for example
+    /// data behind a shared reference, is UB.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn inner(&self) -> *mut T {
-        self.0
+    pub const unsafe fn into_mut(self) -> Ptr<T, Mut, S, D, Tag> {
+        self.convert()
     }
 
     ///
-    /// Makes `ptr` immutable.
+    /// Makes `ptr` no longer Sync while keeping its mutability.
+    ///
+    /// This is a downgrade and therefore safe.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_sync_const(&self) -> SyncConstPtr<T> {
-        SyncConstPtr(self.0)
+    pub const fn into_send(self) -> Ptr<T, M, SendOnly, D, Tag> {
+        self.convert()
     }
 
     ///
-    /// Makes `ptr` immutable and no longer Sync.
+    /// Makes `ptr` Sync while keeping its mutability.
+    ///
+    /// # Safety
+    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_send_const(&self) -> SendConstPtr<T> {
-        SendConstPtr(self.0)
+    pub const unsafe fn into_sync(self) -> Ptr<T, M, SendSync, D, Tag> {
+        self.convert()
     }
 
     ///
-    /// This is equivalent to `.clone()` and does nothing.
+    /// Removes the `Deref` implementation, only the inherent methods can be called afterwards.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_sync_mut(&self) -> Self {
-        Self(self.0)
+    pub const fn into_explicit(self) -> Ptr<T, M, S, NoDeref, Tag> {
+        Ptr::wrap(self.0)
     }
 
     ///
-    /// Makes `ptr` no longer Sync.
+    /// Adds the `Deref` implementation to the raw pointer back.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_send_mut(&self) -> SendMutPtr<T> {
-        SendMutPtr(self.0)
-    }
-}
-
-impl<T> Deref for SyncMutPtr<T> {
-    type Target = *mut T;
-
-    #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    pub const fn into_auto_deref(self) -> Ptr<T, M, S, AutoDeref, Tag> {
+        Ptr::wrap(self.0)
     }
-}
 
-impl<T> From<SyncMutPtr<T>> for *mut T {
+    ///
+    /// Changes the tag of `ptr` to `U` while keeping everything else.
+    ///
+    /// ```
+    /// use core::ffi::c_void;
+    /// use sync_ptr::SyncMutPtr;
+    ///
+    /// enum Window {}
+    /// enum Texture {}
+    ///
+    /// fn destroy_window(_window: SyncMutPtr<c_void, Window>) {}
+    ///
+    /// let window = SyncMutPtr::<c_void>::null().retag::<Window>();
+    /// destroy_window(window);
+    /// ```
+    ///
+    /// Pointers with a different tag are rejected:
+    /// ```compile_fail
+    /// # use core::ffi::c_void;
+    /// # use sync_ptr::SyncMutPtr;
+    /// # enum Window {}
+    /// # enum Texture {}
+    /// # fn destroy_window(_window: SyncMutPtr<c_void, Window>) {}
+    /// let texture = SyncMutPtr::<c_void>::null().retag::<Texture>();
+    /// destroy_window(texture);
+    /// ```
+    ///
     #[inline(always)]
-    fn from(val: SyncMutPtr<T>) -> Self {
-        val.inner()
+    #[must_use]
+    pub const fn retag<U>(self) -> Ptr<T, M, S, D, U> {
+        Ptr::wrap(self.0)
     }
-}
 
-impl<T> From<SyncMutPtr<T>> for *const T {
+    ///
+    /// Returns true if `ptr` is null.
+    ///
+    /// For pointers to unsized data only the address is checked, the metadata is ignored.
+    ///
     #[inline(always)]
-    fn from(val: SyncMutPtr<T>) -> Self {
-        val.inner()
+    #[must_use]
+    pub const fn is_null(&self) -> bool {
+        self.0.is_null()
     }
-}
 
-///
-/// Wrapped const raw pointer that is Send+Sync
-///
-#[repr(transparent)]
-pub struct SyncConstPtr<T>(*const T);
-
-unsafe impl<T> Sync for SyncConstPtr<T> {}
-unsafe impl<T> Send for SyncConstPtr<T> {}
-
-trait_impl!(SyncConstPtr);
-
-impl<T> SyncConstPtr<T> {
     ///
-    /// Makes `ptr` Send+Sync
+    /// Returns a shared reference to the value behind `ptr`, or `None` if `ptr` is null.
     ///
     /// # Safety
-    /// The `ptr` parameter must be able to handle being sent and used in other threads concurrently,
-    /// or special care must be taken when using the wrapped `ptr` to not use it
-    /// in any way in other threads.
+    /// Same as `pointer::as_ref`.
     ///
     #[inline(always)]
     #[must_use]
-    pub const unsafe fn new(ptr: *const T) -> Self {
-        Self(ptr)
+    pub const unsafe fn as_ref<'a>(&self) -> Option<&'a T> {
+        self.0.as_ref()
     }
 
     ///
-    /// Makes a Send+Sync null ptr.
+    /// Casts `ptr` to another data type while keeping it wrapped.
+    ///
+    /// Pointer metadata (slice length, vtable) is discarded because `Y` is always a sized type.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn null() -> Self {
-        Self(core::ptr::null())
+    pub const fn cast<Y>(&self) -> Ptr<Y, M, S, D, Tag> {
+        Ptr::wrap(self.0.cast())
     }
 
     ///
-    /// Casts `ptr` to another data type while keeping it Send+Sync.
+    /// Returns the address portion of `ptr`, without exposing its provenance.
+    ///
+    /// See `pointer::addr` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn cast<Y>(&self) -> SyncConstPtr<Y> {
-        SyncConstPtr(self.0.cast())
+    pub fn addr(&self) -> usize {
+        self.0.addr()
     }
 
     ///
-    /// Returns inner `ptr` which is then no longer Send+Sync.
+    /// Returns true if `ptr` and `other` have the same address,
+    /// ignoring the pointee type, metadata (slice length, vtable) and the wrapper type.
+    /// `other` can be a raw pointer, a `NonNull` or any wrapper that implements `PtrWrapper`.
+    ///
+    /// See `core::ptr::addr_eq` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn inner(&self) -> *const T {
-        self.0
+    pub fn addr_eq<U: ?Sized>(&self, other: impl ToConstPtr<U>) -> bool {
+        core::ptr::addr_eq(self.0, other.to_const_ptr())
     }
 
     ///
-    /// This is equivalent to `.clone()` and does nothing.
+    /// Creates a new pointer with the given address and the provenance of `ptr` while keeping it wrapped.
+    ///
+    /// See `pointer::with_addr` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_sync_const(&self) -> Self {
-        Self(self.0)
+    pub fn with_addr(&self, addr: usize) -> Self {
+        Self::wrap(self.0.with_addr(addr))
     }
 
     ///
-    /// Makes this `ptr` no longer Sync.
+    /// Creates a new pointer by mapping the address of `ptr` to a new address
+    /// with the provenance of `ptr` while keeping it wrapped.
+    ///
+    /// See `pointer::map_addr` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_send_const(&self) -> SendConstPtr<T> {
-        SendConstPtr(self.0)
+    pub fn map_addr(&self, f: impl FnOnce(usize) -> usize) -> Self {
+        Self::wrap(self.0.map_addr(f))
     }
 
     ///
-    /// Makes this `ptr` mutable
+    /// Exposes the provenance of `ptr` and returns its address.
     ///
-    /// # Safety
-    /// Writing to immutable data is UB.
+    /// The returned address can be turned back into a wrapped pointer with `with_exposed_provenance`.
+    /// See `pointer::expose_provenance` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_sync_mut(&self) -> SyncMutPtr<T> {
-        SyncMutPtr(self.0.cast_mut())
+    pub fn expose_provenance(&self) -> usize {
+        self.0.expose_provenance()
     }
 
     ///
-    /// Makes this `ptr` mutable and no longer Sync.
+    /// Adds `count` bytes to `ptr` while keeping it wrapped.
     ///
     /// # Safety
-    /// Writing to immutable data is UB.
+    /// Same as `pointer::byte_add`.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_send_mut(&self) -> SendMutPtr<T> {
-        SendMutPtr(self.0.cast_mut())
-    }
-}
-
-impl<T> Deref for SyncConstPtr<T> {
-    type Target = *const T;
-
-    #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    pub const unsafe fn byte_add(&self, count: usize) -> Self {
+        Self::wrap(self.0.byte_add(count))
     }
-}
 
-impl<T> From<SyncConstPtr<T>> for *const T {
+    ///
+    /// Subtracts `count` bytes from `ptr` while keeping it wrapped.
+    ///
+    /// # Safety
+    /// Same as `pointer::byte_sub`.
+    ///
     #[inline(always)]
-    fn from(val: SyncConstPtr<T>) -> Self {
-        val.inner()
+    #[must_use]
+    pub const unsafe fn byte_sub(&self, count: usize) -> Self {
+        Self::wrap(self.0.byte_sub(count))
     }
-}
-
-///
-/// Wrapped mutable raw pointer that is Send but not Sync
-///
-#[repr(transparent)]
-pub struct SendMutPtr<T>(*mut T);
-
-unsafe impl<T> Send for SendMutPtr<T> {}
-
-trait_impl!(SendMutPtr);
 
-impl<T> SendMutPtr<T> {
     ///
-    /// Makes `ptr` Send
+    /// Offsets `ptr` by `count` bytes while keeping it wrapped.
     ///
     /// # Safety
-    /// The `ptr` parameter must be able to handle being sent to other threads
-    /// or special care must be taken when using the wrapped `ptr` to not use it
-    /// in any way in other threads.
+    /// Same as `pointer::byte_offset`.
     ///
     #[inline(always)]
     #[must_use]
-    pub const unsafe fn new(ptr: *mut T) -> Self {
-        Self(ptr)
+    pub const unsafe fn byte_offset(&self, count: isize) -> Self {
+        Self::wrap(self.0.byte_offset(count))
     }
+
     ///
-    /// Makes a Send null ptr.
+    /// Adds `count` bytes to `ptr` using wrapping arithmetic while keeping it wrapped.
+    ///
+    /// See `pointer::wrapping_byte_add` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn null() -> Self {
-        Self(core::ptr::null_mut())
+    pub const fn wrapping_byte_add(&self, count: usize) -> Self {
+        Self::wrap(self.0.wrapping_byte_add(count))
     }
 
     ///
-    /// Casts `ptr` to another data type while keeping it Send.
+    /// Subtracts `count` bytes from `ptr` using wrapping arithmetic while keeping it wrapped.
+    ///
+    /// See `pointer::wrapping_byte_sub` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn cast<Y>(&self) -> SendMutPtr<Y> {
-        SendMutPtr(self.0.cast())
+    pub const fn wrapping_byte_sub(&self, count: usize) -> Self {
+        Self::wrap(self.0.wrapping_byte_sub(count))
     }
 
     ///
-    /// Returns inner `ptr` which is then no longer Send.
+    /// Offsets `ptr` by `count` bytes using wrapping arithmetic while keeping it wrapped.
+    ///
+    /// See `pointer::wrapping_byte_offset` for details.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn inner(&self) -> *mut T {
-        self.0
+    pub const fn wrapping_byte_offset(&self, count: isize) -> Self {
+        Self::wrap(self.0.wrapping_byte_offset(count))
     }
 
     ///
-    /// Makes this `ptr` Sync
+    /// Calculates the distance between `ptr` and `origin` in bytes.
     ///
     /// # Safety
-    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
-    /// or special care must be taken when using the wrapped `ptr` to not use it
-    /// in any way in other threads.
+    /// Same as `pointer::byte_offset_from`.
     ///
     #[inline(always)]
     #[must_use]
-    pub const unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
-        SyncConstPtr(self.0)
+    pub const unsafe fn byte_offset_from(&self, origin: Self) -> isize {
+        self.0.byte_offset_from(origin.0)
     }
 
     ///
-    /// Makes this `ptr` const.
+    /// Returns true if the address of `ptr` is a multiple of `align`.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_send_const(&self) -> SendConstPtr<T> {
-        SendConstPtr(self.0)
+    pub fn is_aligned_to(&self, align: usize) -> bool {
+        assert!(align.is_power_of_two(), "align is not a power of two");
+        self.addr() & (align - 1) == 0
     }
 
     ///
-    /// Makes this `ptr` Sync
+    /// Rounds the address of `ptr` up to the next multiple of `align` while keeping it wrapped.
     ///
-    /// # Safety
-    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
-    /// or special care must be taken when using the wrapped `ptr` to not use it
-    /// in any way in other threads.
+    /// The provenance of `ptr` is kept, the returned pointer may be out of bounds.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two.
     ///
     #[inline(always)]
     #[must_use]
-    pub const unsafe fn as_sync_mut(&self) -> SyncMutPtr<T> {
-        SyncMutPtr(self.0)
+    pub fn align_up(&self, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "align is not a power of two");
+        self.map_addr(|addr| addr.wrapping_add(align - 1) & !(align - 1))
     }
 
     ///
-    /// This is equivalent to `.clone()` and does nothing.
+    /// Rounds the address of `ptr` down to the previous multiple of `align` while keeping it wrapped.
+    ///
+    /// The provenance of `ptr` is kept, the returned pointer may be out of bounds.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn as_send_mut(&self) -> Self {
-        Self(self.0)
+    pub fn align_down(&self, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "align is not a power of two");
+        self.map_addr(|addr| addr & !(align - 1))
     }
 }
 
-impl<T> Deref for SendMutPtr<T> {
-    type Target = *mut T;
-
+impl<T, M: Mutability, S: ThreadSafety, D: DerefMode> Ptr<T, M, S, D> {
+    ///
+    /// Makes a wrapped null ptr.
+    ///
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    #[must_use]
+    pub const fn null() -> Self {
+        Self::wrap(core::ptr::null())
     }
-}
 
-impl<T> From<SendMutPtr<T>> for *mut T {
+    ///
+    /// Makes a wrapped pointer with the given address and no provenance.
+    ///
+    /// The pointer is not valid for any reads or writes, but it can be compared or used as a sentinel.
+    ///
     #[inline(always)]
-    fn from(val: SendMutPtr<T>) -> Self {
-        val.inner()
+    #[must_use]
+    pub const fn without_provenance(addr: usize) -> Self {
+        Self::wrap(core::ptr::without_provenance(addr))
     }
-}
 
-impl<T> From<SendMutPtr<T>> for *const T {
+    ///
+    /// Makes a wrapped dangling pointer that is well aligned but not valid for reads or writes.
+    ///
     #[inline(always)]
-    fn from(val: SendMutPtr<T>) -> Self {
-        val.inner()
+    #[must_use]
+    pub const fn dangling() -> Self {
+        Self::wrap(core::ptr::dangling())
     }
-}
-
-///
-/// Wrapped const raw pointer that is Send but not Sync
-///
-#[repr(transparent)]
-pub struct SendConstPtr<T>(*const T);
-
-unsafe impl<T> Send for SendConstPtr<T> {}
-
-trait_impl!(SendConstPtr);
 
-impl<T> SendConstPtr<T> {
     ///
-    /// Makes `ptr` Send
+    /// Makes a wrapped pointer from `addr` and a previously exposed provenance.
+    ///
+    /// See `core::ptr::with_exposed_provenance` for details.
     ///
     /// # Safety
-    /// The `ptr` parameter must be able to handle being sent to other threads
-    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// The pointer must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped pointer to not use it
     /// in any way in other threads.
     ///
     #[inline(always)]
     #[must_use]
-    pub const unsafe fn new(ptr: *const T) -> Self {
-        Self(ptr)
+    pub const unsafe fn with_exposed_provenance(addr: usize) -> Self {
+        Self::wrap(core::ptr::with_exposed_provenance(addr))
     }
 
     ///
-    /// Makes a Send null ptr.
+    /// Makes a wrapped pointer from `addr` and a previously exposed provenance
+    /// after checking that it is not null and aligned for `T`.
+    ///
+    /// # Errors
+    /// Returns `PtrError::Null` or `PtrError::Misaligned` if `addr` fails the respective check.
+    ///
+    /// # Safety
+    /// The pointer must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped pointer to not use it
+    /// in any way in other threads.
     ///
     #[inline(always)]
-    #[must_use]
-    pub const fn null() -> Self {
-        Self(core::ptr::null())
+    pub unsafe fn try_from_raw(addr: usize) -> Result<Self, PtrError> {
+        PtrError::check::<T>(addr, None)?;
+        Ok(Self::with_exposed_provenance(addr))
     }
+}
 
+impl<T, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Ptr<T, M, S, D, Tag> {
     ///
-    /// Casts `ptr` to another data type while keeping it Send.
+    /// Adds `count` elements of `T` to `ptr` while keeping it wrapped.
+    ///
+    /// # Safety
+    /// Same as `pointer::add`.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn cast<Y>(&self) -> SendConstPtr<Y> {
-        SendConstPtr(self.0.cast())
+    pub const unsafe fn add(&self, count: usize) -> Self {
+        Self::wrap(self.0.add(count))
     }
 
     ///
-    /// Returns inner `ptr` which is then no longer Send.
+    /// Subtracts `count` elements of `T` from `ptr` while keeping it wrapped.
+    ///
+    /// # Safety
+    /// Same as `pointer::sub`.
     ///
     #[inline(always)]
     #[must_use]
-    pub const fn inner(&self) -> *const T {
-        self.0
+    pub const unsafe fn sub(&self, count: usize) -> Self {
+        Self::wrap(self.0.sub(count))
     }
 
     ///
-    /// Makes this `ptr` Sync
+    /// Offsets `ptr` by `count` elements of `T` while keeping it wrapped.
     ///
     /// # Safety
-    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
-    /// or special care must be taken when using the wrapped `ptr` to not use it
-    /// in any way in other threads.
+    /// Same as `pointer::offset`.
     ///
     #[inline(always)]
     #[must_use]
+    pub const unsafe fn offset(&self, count: isize) -> Self {
+        Self::wrap(self.0.offset(count))
+    }
+
+    ///
+    /// Adds `count` elements of `T` to `ptr` using wrapping arithmetic while keeping it wrapped.
+    ///
+    /// See `pointer::wrapping_add` for details.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_add(&self, count: usize) -> Self {
+        Self::wrap(self.0.wrapping_add(count))
+    }
+
+    ///
+    /// Subtracts `count` elements of `T` from `ptr` using wrapping arithmetic while keeping it wrapped.
+    ///
+    /// See `pointer::wrapping_sub` for details.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_sub(&self, count: usize) -> Self {
+        Self::wrap(self.0.wrapping_sub(count))
+    }
+
+    ///
+    /// Offsets `ptr` by `count` elements of `T` using wrapping arithmetic while keeping it wrapped.
+    ///
+    /// See `pointer::wrapping_offset` for details.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_offset(&self, count: isize) -> Self {
+        Self::wrap(self.0.wrapping_offset(count))
+    }
+
+    ///
+    /// Calculates the distance between `ptr` and `origin` in elements of `T`.
+    ///
+    /// # Safety
+    /// Same as `pointer::offset_from`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn offset_from(&self, origin: Self) -> isize {
+        self.0.offset_from(origin.0)
+    }
+
+    ///
+    /// Reads the value from `ptr` without moving it.
+    ///
+    /// # Safety
+    /// Same as `pointer::read`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn read(&self) -> T {
+        self.0.read()
+    }
+
+    ///
+    /// Reads the value from `ptr` without moving it, `ptr` does not need to be aligned.
+    ///
+    /// # Safety
+    /// Same as `pointer::read_unaligned`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn read_unaligned(&self) -> T {
+        self.0.read_unaligned()
+    }
+
+    ///
+    /// Performs a volatile read of the value from `ptr` without moving it.
+    ///
+    /// # Safety
+    /// Same as `pointer::read_volatile`.
+    ///
+    #[inline(always)]
+    #[allow(clippy::must_use_candidate)] // A volatile read may be performed only for its side effects.
+    pub unsafe fn read_volatile(&self) -> T {
+        self.0.read_volatile()
+    }
+
+    ///
+    /// Copies `count * size_of::<T>()` bytes from `ptr` to `dest`. The source and destination may overlap.
+    ///
+    /// # Safety
+    /// Same as `pointer::copy_to`.
+    ///
+    #[inline(always)]
+    pub unsafe fn copy_to(&self, dest: impl Into<*mut T>, count: usize) {
+        self.0.copy_to(dest.into(), count);
+    }
+
+    ///
+    /// Copies `count * size_of::<T>()` bytes from `ptr` to `dest`. The source and destination must not overlap.
+    ///
+    /// # Safety
+    /// Same as `pointer::copy_to_nonoverlapping`.
+    ///
+    #[inline(always)]
+    pub unsafe fn copy_to_nonoverlapping(&self, dest: impl Into<*mut T>, count: usize) {
+        self.0.copy_to_nonoverlapping(dest.into(), count);
+    }
+
+    ///
+    /// Returns true if `ptr` is properly aligned for `T`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn is_aligned(&self) -> bool {
+        self.0.is_aligned()
+    }
+
+    ///
+    /// Computes the number of elements of `T` that `ptr` needs to be offset by to be aligned to `align`.
+    ///
+    /// See `pointer::align_offset` for details.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn align_offset(&self, align: usize) -> usize {
+        self.0.align_offset(align)
+    }
+}
+
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode> Ptr<T, Mut, S, D> {
+    ///
+    /// Makes `ptr` Send, and also Sync if `S` is `SendSync`.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new(ptr: *mut T) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode, Tag> Ptr<T, Mut, S, D, Tag> {
+    ///
+    /// Returns inner `ptr` which is then no longer Send or Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *mut T {
+        self.0.cast_mut()
+    }
+
+    ///
+    /// Returns a mutable reference to the value behind `ptr`, or `None` if `ptr` is null.
+    ///
+    /// # Safety
+    /// Same as `pointer::as_mut`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_mut<'a>(&self) -> Option<&'a mut T> {
+        self.inner().as_mut()
+    }
+
+    ///
+    /// Executes the destructor of the value behind `ptr`.
+    ///
+    /// # Safety
+    /// Same as `core::ptr::drop_in_place`.
+    ///
+    #[inline(always)]
+    pub unsafe fn drop_in_place(&self) {
+        core::ptr::drop_in_place(self.inner());
+    }
+
+    ///
+    /// Turns `ptr` back into the box it was made from.
+    ///
+    /// # Safety
+    /// Same as `Box::from_raw`, `ptr` must have been made by `from_box` or `Box::into_raw`
+    /// and must not be used afterward.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn into_box(self) -> Box<T> {
+        Box::from_raw(self.inner())
+    }
+}
+
+impl<T, S: ThreadSafety, D: DerefMode, Tag> Ptr<T, Mut, S, D, Tag> {
+    ///
+    /// Copies `count * size_of::<T>()` bytes from `src` to `ptr`. The source and destination may overlap.
+    ///
+    /// # Safety
+    /// Same as `pointer::copy_from`.
+    ///
+    #[inline(always)]
+    pub unsafe fn copy_from(&self, src: impl Into<*const T>, count: usize) {
+        self.inner().copy_from(src.into(), count);
+    }
+
+    ///
+    /// Copies `count * size_of::<T>()` bytes from `src` to `ptr`. The source and destination must not overlap.
+    ///
+    /// # Safety
+    /// Same as `pointer::copy_from_nonoverlapping`.
+    ///
+    #[inline(always)]
+    pub unsafe fn copy_from_nonoverlapping(&self, src: impl Into<*const T>, count: usize) {
+        self.inner().copy_from_nonoverlapping(src.into(), count);
+    }
+
+    ///
+    /// Overwrites the memory at `ptr` with `val` without reading or dropping the old value.
+    ///
+    /// # Safety
+    /// Same as `pointer::write`.
+    ///
+    #[inline(always)]
+    pub const unsafe fn write(&self, val: T) {
+        self.inner().write(val);
+    }
+
+    ///
+    /// Overwrites the memory at `ptr` with `val` without reading or dropping the old value, `ptr` does not need to be aligned.
+    ///
+    /// # Safety
+    /// Same as `pointer::write_unaligned`.
+    ///
+    #[inline(always)]
+    pub const unsafe fn write_unaligned(&self, val: T) {
+        self.inner().write_unaligned(val);
+    }
+
+    ///
+    /// Performs a volatile write of `val` to the memory at `ptr` without reading or dropping the old value.
+    ///
+    /// # Safety
+    /// Same as `pointer::write_volatile`.
+    ///
+    #[inline(always)]
+    pub unsafe fn write_volatile(&self, val: T) {
+        self.inner().write_volatile(val);
+    }
+
+    ///
+    /// Sets `count * size_of::<T>()` bytes of memory starting at `ptr` to `val`.
+    ///
+    /// # Safety
+    /// Same as `pointer::write_bytes`.
+    ///
+    #[inline(always)]
+    pub const unsafe fn write_bytes(&self, val: u8, count: usize) {
+        self.inner().write_bytes(val, count);
+    }
+
+    ///
+    /// Replaces the value at `ptr` with `src`, returning the old value without dropping either.
+    ///
+    /// # Safety
+    /// Same as `pointer::replace`.
+    ///
+    #[inline(always)]
+    pub const unsafe fn replace(&self, src: T) -> T {
+        self.inner().replace(src)
+    }
+
+    ///
+    /// Swaps the values at `ptr` and `with`, they may overlap.
+    ///
+    /// # Safety
+    /// Same as `pointer::swap`.
+    ///
+    #[inline(always)]
+    pub unsafe fn swap(&self, with: impl Into<*mut T>) {
+        self.inner().swap(with.into());
+    }
+}
+
+impl<T, S: ThreadSafety, D: DerefMode> Ptr<T, Mut, S, D> {
+    ///
+    /// Makes `ptr` Send, and also Sync if `S` is `SendSync`, after checking that it is not null and aligned for `T`.
+    ///
+    /// # Errors
+    /// Returns `PtrError::Null` or `PtrError::Misaligned` if `ptr` fails the respective check.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    pub unsafe fn try_new(ptr: *mut T) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), None)?;
+        Ok(Self::wrap(ptr))
+    }
+
+    ///
+    /// Makes `ptr` Send, and also Sync if `S` is `SendSync`, after checking that it is not null,
+    /// aligned for `T` and that the `size_of::<T>()` bytes it points to lie completely within `range`.
+    ///
+    /// # Errors
+    /// Returns `PtrError::Null`, `PtrError::Misaligned` or `PtrError::OutOfRange`
+    /// if `ptr` fails the respective check.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    pub unsafe fn try_new_in(ptr: *mut T, range: Range<usize>) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), Some(range))?;
+        Ok(Self::wrap(ptr))
+    }
+}
+
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode> Ptr<T, Const, S, D> {
+    ///
+    /// Makes `ptr` Send, and also Sync if `S` is `SendSync`.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new(ptr: *const T) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode, Tag> Ptr<T, Const, S, D, Tag> {
+    ///
+    /// Returns inner `ptr` which is then no longer Send or Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *const T {
+        self.0
+    }
+
+    ///
+    /// Turns `ptr` back into the `Arc` it was made from, without changing the reference count.
+    ///
+    /// # Safety
+    /// Same as `Arc::from_raw`, `ptr` must have been made by `from_arc` or `Arc::into_raw`
+    /// and each call gives up one strong reference, so `ptr` must not be used afterward
+    /// unless another strong reference is still held.
+    ///
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn into_arc(self) -> Arc<T> {
+        Arc::from_raw(self.0)
+    }
+
+    ///
+    /// Returns a new `Arc` to the value behind `ptr` and increments the strong reference count,
+    /// so `ptr` stays valid.
+    ///
+    /// # Safety
+    /// `ptr` must have been made by `from_arc` or `Arc::into_raw`
+    /// and the strong reference it holds must not have been given up with `into_arc`.
+    ///
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn clone_arc(&self) -> Arc<T> {
+        Arc::increment_strong_count(self.0);
+        Arc::from_raw(self.0)
+    }
+}
+
+impl<T, S: ThreadSafety, D: DerefMode> Ptr<T, Const, S, D> {
+    ///
+    /// Makes `ptr` Send, and also Sync if `S` is `SendSync`, after checking that it is not null and aligned for `T`.
+    ///
+    /// # Errors
+    /// Returns `PtrError::Null` or `PtrError::Misaligned` if `ptr` fails the respective check.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    pub unsafe fn try_new(ptr: *const T) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), None)?;
+        Ok(Self::wrap(ptr))
+    }
+
+    ///
+    /// Makes `ptr` Send, and also Sync if `S` is `SendSync`, after checking that it is not null,
+    /// aligned for `T` and that the `size_of::<T>()` bytes it points to lie completely within `range`.
+    ///
+    /// # Errors
+    /// Returns `PtrError::Null`, `PtrError::Misaligned` or `PtrError::OutOfRange`
+    /// if `ptr` fails the respective check.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads,
+    /// and used in other threads concurrently if `S` is `SendSync`,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    pub unsafe fn try_new_in(ptr: *const T, range: Range<usize>) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), Some(range))?;
+        Ok(Self::wrap(ptr))
+    }
+}
+
+impl<T: ?Sized + Send + Sync, D: DerefMode> Ptr<T, Mut, SendSync, D> {
+    ///
+    /// Makes a Send+Sync pointer to the value behind `val`.
+    ///
+    /// Dereferencing the pointer after the borrow of `val` has ended is still UB.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_mut(val: &mut T) -> Self {
+        Self::wrap(val)
+    }
+
+    ///
+    /// Makes a Send+Sync pointer that owns the value of `val`.
+    ///
+    /// The value is leaked unless the pointer is turned back into a box with `into_box`.
+    ///
+    #[inline(always)]
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn from_box(val: Box<T>) -> Self {
+        Self::wrap(Box::into_raw(val))
+    }
+}
+
+impl<T: ?Sized + Sync, D: DerefMode> Ptr<T, Const, SendSync, D> {
+    ///
+    /// Makes a Send+Sync pointer to the value behind `val`.
+    ///
+    /// Dereferencing the pointer after the borrow of `val` has ended is still UB.
+    ///
+    /// Pointees that are not Sync still need the unsafe `new`:
+    /// ```compile_fail
+    /// let cell = core::cell::Cell::new(1);
+    /// let _ = sync_ptr::SyncConstPtr::from_ref(&cell);
+    /// ```
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_ref(val: &T) -> Self {
+        Self::wrap(val)
+    }
+
+    ///
+    /// Makes a Send+Sync pointer that holds one strong reference of `val`.
+    ///
+    /// This is safe because `Arc<T>` is itself Send+Sync for `T: Send + Sync`.
+    /// The reference is leaked unless it is given back with `into_arc`.
+    ///
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline(always)]
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn from_arc(val: Arc<T>) -> Self
+    where
+        T: Send,
+    {
+        Self::wrap(Arc::into_raw(val))
+    }
+}
+
+impl<T: ?Sized + Send, D: DerefMode> Ptr<T, Mut, SendOnly, D> {
+    ///
+    /// Makes a Send pointer to the value behind `val`.
+    ///
+    /// Dereferencing the pointer after the borrow of `val` has ended is still UB.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_mut(val: &mut T) -> Self {
+        Self::wrap(val)
+    }
+
+    ///
+    /// Makes a Send pointer that owns the value of `val`.
+    ///
+    /// The value is leaked unless the pointer is turned back into a box with `into_box`.
+    ///
+    #[inline(always)]
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn from_box(val: Box<T>) -> Self {
+        Self::wrap(Box::into_raw(val))
+    }
+}
+
+impl<T: ?Sized + Sync, D: DerefMode> Ptr<T, Const, SendOnly, D> {
+    ///
+    /// Makes a Send pointer to the value behind `val`.
+    ///
+    /// Dereferencing the pointer after the borrow of `val` has ended is still UB.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_ref(val: &T) -> Self {
+        Self::wrap(val)
+    }
+}
+
+impl<T, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> Ptr<[T], M, S, D, Tag> {
+    ///
+    /// Creates a wrapped slice pointer from a wrapped pointer to the first element and a length.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn slice_from_raw_parts(data: Ptr<T, M, S, D, Tag>, len: usize) -> Self {
+        Self::wrap(core::ptr::slice_from_raw_parts(data.0, len))
+    }
+
+    ///
+    /// Returns the length of the slice `ptr` points to.
+    ///
+    /// The length is part of the pointer metadata, so this does not access the pointee.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    ///
+    /// Returns true if the slice `ptr` points to has a length of 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    ///
+    /// Casts `ptr` to a slice of another element type while keeping the length and keeping it wrapped.
+    ///
+    /// The length is kept as the number of elements, not the number of bytes.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn cast_slice<Y>(&self) -> Ptr<[Y], M, S, D, Tag> {
+        Ptr::wrap(self.0 as *const [Y])
+    }
+}
+
+impl<T: ?Sized> SyncMutPtr<T> {
+    ///
+    /// Makes `ptr` immutable.
+    ///
+    #[deprecated(note = "use `into_const` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_sync_const(&self) -> SyncConstPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// Makes `ptr` immutable and no longer Sync.
+    ///
+    #[deprecated(note = "use `into_const().into_send()` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_send_const(&self) -> SendConstPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// This is equivalent to `.clone()` and does nothing.
+    ///
+    #[deprecated(note = "use `clone` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_sync_mut(&self) -> Self {
+        self.convert()
+    }
+
+    ///
+    /// Makes `ptr` no longer Sync.
+    ///
+    #[deprecated(note = "use `into_send` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_send_mut(&self) -> SendMutPtr<T> {
+        self.convert()
+    }
+}
+
+impl<T: ?Sized> SyncConstPtr<T> {
+    ///
+    /// This is equivalent to `.clone()` and does nothing.
+    ///
+    #[deprecated(note = "use `clone` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_sync_const(&self) -> Self {
+        self.convert()
+    }
+
+    ///
+    /// Makes this `ptr` no longer Sync.
+    ///
+    #[deprecated(note = "use `into_send` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_send_const(&self) -> SendConstPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// Makes this `ptr` mutable
+    ///
+    /// # Safety
+    /// Writing to immutable data is UB.
+    ///
+    #[deprecated(note = "use the unsafe `into_mut` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_sync_mut(&self) -> SyncMutPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// Makes this `ptr` mutable and no longer Sync.
+    ///
+    /// # Safety
+    /// Writing to immutable data is UB.
+    ///
+    #[deprecated(note = "use the unsafe `into_mut().into_send()` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_send_mut(&self) -> SendMutPtr<T> {
+        self.convert()
+    }
+}
+
+impl<T: ?Sized> SendMutPtr<T> {
+    ///
+    /// Makes this `ptr` Sync
+    ///
+    /// # Safety
+    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[deprecated(note = "use the unsafe `into_sync().into_const()` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// Makes this `ptr` const.
+    ///
+    #[deprecated(note = "use `into_const` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_send_const(&self) -> SendConstPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// Makes this `ptr` Sync
+    ///
+    /// # Safety
+    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[deprecated(note = "use the unsafe `into_sync` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_sync_mut(&self) -> SyncMutPtr<T> {
+        self.convert()
+    }
+
+    ///
+    /// This is equivalent to `.clone()` and does nothing.
+    ///
+    #[deprecated(note = "use `clone` instead")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_send_mut(&self) -> Self {
+        self.convert()
+    }
+}
+
+impl<T: ?Sized> SendConstPtr<T> {
+    ///
+    /// Makes this `ptr` Sync
+    ///
+    /// # Safety
+    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[deprecated(note = "use the unsafe `into_sync` instead")]
+    #[inline(always)]
+    #[must_use]
     pub const unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
-        SyncConstPtr(self.0)
+        self.convert()
     }
 
     ///
     /// This is equivalent to `.clone()` and does nothing.
     ///
+    #[deprecated(note = "use `clone` instead")]
     #[inline(always)]
     #[must_use]
     pub const fn as_send_const(&self) -> Self {
-        Self(self.0)
+        self.convert()
     }
 
     ///
@@ -523,10 +1557,11 @@ impl<T> SendConstPtr<T> {
     ///
     /// `ptr` is also marked as mutable. Writing to immutable data is usually UB.
     ///
+    #[deprecated(note = "use the unsafe `into_sync().into_mut()` instead")]
     #[inline(always)]
     #[must_use]
     pub const unsafe fn as_sync_mut(&self) -> SyncMutPtr<T> {
-        SyncMutPtr(self.0.cast_mut())
+        self.convert()
     }
 
     ///
@@ -535,14 +1570,27 @@ impl<T> SendConstPtr<T> {
     /// # Safety
     /// Writing to immutable data is UB.
     ///
+    #[deprecated(note = "use the unsafe `into_mut` instead")]
     #[inline(always)]
     #[must_use]
     pub const fn as_send_mut(&self) -> SendMutPtr<T> {
-        SendMutPtr(self.0.cast_mut())
+        self.convert()
+    }
+}
+
+/// Safe because the raw pointer is neither Send nor Sync, so this can only give up capabilities.
+impl<T: ?Sized, S: ThreadSafety, Tag> Deref for Ptr<T, Mut, S, AutoDeref, Tag> {
+    type Target = *mut T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `*const T` and `*mut T` have the same layout.
+        unsafe { &*core::ptr::from_ref(&self.0).cast::<*mut T>() }
     }
 }
 
-impl<T> Deref for SendConstPtr<T> {
+/// Safe because the raw pointer is neither Send nor Sync, so this can only give up capabilities.
+impl<T: ?Sized, S: ThreadSafety, Tag> Deref for Ptr<T, Const, S, AutoDeref, Tag> {
     type Target = *const T;
 
     #[inline(always)]
@@ -551,14 +1599,137 @@ impl<T> Deref for SendConstPtr<T> {
     }
 }
 
-impl<T> From<SendConstPtr<T>> for *const T {
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode, Tag> From<Ptr<T, Mut, S, D, Tag>> for *mut T {
     #[inline(always)]
-    fn from(val: SendConstPtr<T>) -> *const T {
+    fn from(val: Ptr<T, Mut, S, D, Tag>) -> Self {
         val.inner()
     }
 }
 
-pub trait FromConstPtr<T>: Sized {
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> From<Ptr<T, M, S, D, Tag>>
+    for *const T
+{
+    #[inline(always)]
+    fn from(val: Ptr<T, M, S, D, Tag>) -> Self {
+        val.0
+    }
+}
+
+/// Safe downgrade, same as `into_const`.
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode, Tag> From<Ptr<T, Mut, S, D, Tag>>
+    for Ptr<T, Const, S, D, Tag>
+{
+    #[inline(always)]
+    fn from(val: Ptr<T, Mut, S, D, Tag>) -> Self {
+        val.into_const()
+    }
+}
+
+/// Safe downgrade, same as `into_send`.
+impl<T: ?Sized, M: Mutability, D: DerefMode, Tag> From<Ptr<T, M, SendSync, D, Tag>>
+    for Ptr<T, M, SendOnly, D, Tag>
+{
+    #[inline(always)]
+    fn from(val: Ptr<T, M, SendSync, D, Tag>) -> Self {
+        val.into_send()
+    }
+}
+
+/// Safe downgrade, same as `into_const().into_send()`.
+impl<T: ?Sized, D: DerefMode, Tag> From<Ptr<T, Mut, SendSync, D, Tag>>
+    for Ptr<T, Const, SendOnly, D, Tag>
+{
+    #[inline(always)]
+    fn from(val: Ptr<T, Mut, SendSync, D, Tag>) -> Self {
+        val.into_const().into_send()
+    }
+}
+
+/// Use the unsafe `try_new` for pointee types that are not `Send + Sync`.
+impl<T: Send + Sync, D: DerefMode> TryFrom<*mut T> for Ptr<T, Mut, SendSync, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(ptr: *mut T) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), None)?;
+        Ok(Self::wrap(ptr))
+    }
+}
+
+/// Use the unsafe `try_from_raw` for pointee types that are not `Send + Sync`.
+impl<T: Send + Sync, D: DerefMode> TryFrom<usize> for Ptr<T, Mut, SendSync, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(addr: usize) -> Result<Self, PtrError> {
+        unsafe { Self::try_from_raw(addr) }
+    }
+}
+
+/// Use the unsafe `try_new` for pointee types that are not `Sync`.
+impl<T: Sync, D: DerefMode> TryFrom<*const T> for Ptr<T, Const, SendSync, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(ptr: *const T) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), None)?;
+        Ok(Self::wrap(ptr))
+    }
+}
+
+/// Use the unsafe `try_from_raw` for pointee types that are not `Sync`.
+impl<T: Sync, D: DerefMode> TryFrom<usize> for Ptr<T, Const, SendSync, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(addr: usize) -> Result<Self, PtrError> {
+        unsafe { Self::try_from_raw(addr) }
+    }
+}
+
+/// Use the unsafe `try_new` for pointee types that are not `Send`.
+impl<T: Send, D: DerefMode> TryFrom<*mut T> for Ptr<T, Mut, SendOnly, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(ptr: *mut T) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), None)?;
+        Ok(Self::wrap(ptr))
+    }
+}
+
+/// Use the unsafe `try_from_raw` for pointee types that are not `Send`.
+impl<T: Send, D: DerefMode> TryFrom<usize> for Ptr<T, Mut, SendOnly, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(addr: usize) -> Result<Self, PtrError> {
+        unsafe { Self::try_from_raw(addr) }
+    }
+}
+
+/// Needs `T: Sync` like sending a `&T` does, use the unsafe `try_new` for other pointee types.
+impl<T: Sync, D: DerefMode> TryFrom<*const T> for Ptr<T, Const, SendOnly, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(ptr: *const T) -> Result<Self, PtrError> {
+        PtrError::check::<T>(ptr.addr(), None)?;
+        Ok(Self::wrap(ptr))
+    }
+}
+
+/// Needs `T: Sync` like sending a `&T` does, use the unsafe `try_from_raw` for other pointee types.
+impl<T: Sync, D: DerefMode> TryFrom<usize> for Ptr<T, Const, SendOnly, D> {
+    type Error = PtrError;
+
+    #[inline(always)]
+    fn try_from(addr: usize) -> Result<Self, PtrError> {
+        unsafe { Self::try_from_raw(addr) }
+    }
+}
+
+pub trait FromConstPtr<T: ?Sized>: Sized {
     ///
     /// Makes `self` immutable and Send+Sync
     ///
@@ -580,7 +1751,7 @@ pub trait FromConstPtr<T>: Sized {
     unsafe fn as_send_const(&self) -> SendConstPtr<T>;
 }
 
-pub trait FromMutPtr<T>: FromConstPtr<T> {
+pub trait FromMutPtr<T: ?Sized>: FromConstPtr<T> {
     ///
     /// Makes `self` Send+Sync
     ///
@@ -602,38 +1773,154 @@ pub trait FromMutPtr<T>: FromConstPtr<T> {
     unsafe fn as_send_mut(&self) -> SendMutPtr<T>;
 }
 
-impl<T> FromConstPtr<T> for *const T {
+///
+/// By-value conversions for `&mut T`, which `FromMutPtr` cannot offer because its methods
+/// only borrow `self` and can therefore only reborrow the pointee as shared.
+///
+pub trait IntoMutPtr<T: ?Sized> {
+    ///
+    /// Consumes the mutable reference and makes a Send+Sync pointer to its pointee.
+    ///
+    /// # Safety
+    /// The pointee must be able to handle being sent to and used concurrently by other threads,
+    /// or special care must be taken when using the wrapped pointer to not use it
+    /// in any way in other threads.
+    /// Dereferencing the pointer after the borrow has ended is UB.
+    ///
+    unsafe fn into_sync_mut(self) -> SyncMutPtr<T>;
+
+    ///
+    /// Consumes the mutable reference and makes a Send pointer to its pointee.
+    ///
+    /// # Safety
+    /// The pointee must be able to handle being sent to other threads
+    /// or special care must be taken when using the wrapped pointer to not use it
+    /// in any way in other threads.
+    /// Dereferencing the pointer after the borrow has ended is UB.
+    ///
+    unsafe fn into_send_mut(self) -> SendMutPtr<T>;
+}
+
+impl<T: ?Sized> IntoMutPtr<T> for &mut T {
+    #[inline(always)]
+    unsafe fn into_sync_mut(self) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(core::ptr::from_mut(self))
+    }
+
+    #[inline(always)]
+    unsafe fn into_send_mut(self) -> SendMutPtr<T> {
+        SendMutPtr::wrap(core::ptr::from_mut(self))
+    }
+}
+
+impl<T: ?Sized> FromConstPtr<T> for *const T {
+    #[inline(always)]
+    unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
+        SyncConstPtr::wrap(*self)
+    }
+
+    #[inline(always)]
+    unsafe fn as_send_const(&self) -> SendConstPtr<T> {
+        SendConstPtr::wrap(*self)
+    }
+}
+
+impl<T: ?Sized> FromConstPtr<T> for *mut T {
+    #[inline(always)]
+    unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
+        SyncConstPtr::wrap(*self)
+    }
+
+    #[inline(always)]
+    unsafe fn as_send_const(&self) -> SendConstPtr<T> {
+        SendConstPtr::wrap(*self)
+    }
+}
+
+impl<T: ?Sized> FromMutPtr<T> for *mut T {
+    #[inline(always)]
+    unsafe fn as_sync_mut(&self) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(*self)
+    }
+
+    #[inline(always)]
+    unsafe fn as_send_mut(&self) -> SendMutPtr<T> {
+        SendMutPtr::wrap(*self)
+    }
+}
+
+impl<T: ?Sized> FromConstPtr<T> for &T {
+    #[inline(always)]
+    unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
+        SyncConstPtr::wrap(*self)
+    }
+
+    #[inline(always)]
+    unsafe fn as_send_const(&self) -> SendConstPtr<T> {
+        SendConstPtr::wrap(*self)
+    }
+}
+
+/// Only the const conversions are offered, the trait methods take `&self` and can only reborrow
+/// the pointee as shared. Use `IntoMutPtr` or `SyncMutPtr::from_mut` for a writable pointer.
+impl<T: ?Sized> FromConstPtr<T> for &mut T {
+    #[inline(always)]
+    unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
+        SyncConstPtr::wrap(&raw const **self)
+    }
+
+    #[inline(always)]
+    unsafe fn as_send_const(&self) -> SendConstPtr<T> {
+        SendConstPtr::wrap(&raw const **self)
+    }
+}
+
+impl<T: ?Sized> FromConstPtr<T> for NonNull<T> {
     #[inline(always)]
     unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
-        SyncConstPtr(self.cast())
+        SyncConstPtr::wrap(self.as_ptr())
     }
 
     #[inline(always)]
     unsafe fn as_send_const(&self) -> SendConstPtr<T> {
-        SendConstPtr(self.cast())
+        SendConstPtr::wrap(self.as_ptr())
+    }
+}
+
+impl<T: ?Sized> FromMutPtr<T> for NonNull<T> {
+    #[inline(always)]
+    unsafe fn as_sync_mut(&self) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn as_send_mut(&self) -> SendMutPtr<T> {
+        SendMutPtr::wrap(self.as_ptr())
     }
 }
 
-impl<T> FromConstPtr<T> for *mut T {
+impl<T: ?Sized> FromConstPtr<T> for &UnsafeCell<T> {
     #[inline(always)]
     unsafe fn as_sync_const(&self) -> SyncConstPtr<T> {
-        SyncConstPtr(self.cast())
+        SyncConstPtr::wrap(self.get())
     }
 
     #[inline(always)]
     unsafe fn as_send_const(&self) -> SendConstPtr<T> {
-        SendConstPtr(self.cast())
+        SendConstPtr::wrap(self.get())
     }
 }
 
-impl<T> FromMutPtr<T> for *mut T {
+/// The pointer comes from `UnsafeCell::get`, so it can be written through
+/// as long as no reference to the contents is alive.
+impl<T: ?Sized> FromMutPtr<T> for &UnsafeCell<T> {
     #[inline(always)]
     unsafe fn as_sync_mut(&self) -> SyncMutPtr<T> {
-        SyncMutPtr(self.cast())
+        SyncMutPtr::wrap(self.get())
     }
 
     #[inline(always)]
     unsafe fn as_send_mut(&self) -> SendMutPtr<T> {
-        SendMutPtr(self.cast())
+        SendMutPtr::wrap(self.get())
     }
 }
@@ -0,0 +1,115 @@
+//! Marker types for the type parameters of [`Ptr`](crate::Ptr).
+//!
+//! `M` is either [`Mut`] or [`Const`], `S` is either [`SendSync`] or [`SendOnly`]
+//! and `D` is either [`AutoDeref`] or [`NoDeref`].
+//! All three traits are sealed, so these are the only possible markers.
+
+/// Prevents implementations of the marker traits outside of this crate.
+mod sealed {
+    /// Implemented by every marker type in this module.
+    pub trait Sealed {}
+}
+
+///
+/// Mutability of the wrapped pointer, implemented by [`Mut`] and [`Const`].
+///
+pub trait Mutability: sealed::Sealed + 'static {
+    /// The raw pointer type of this mutability, either `*mut T` or `*const T`.
+    type Raw<T: ?Sized>: Copy;
+
+    /// Infix of the type name used in `Debug` output.
+    const NAME: &'static str;
+
+    ///
+    /// Converts `ptr` to the raw pointer type of this mutability.
+    ///
+    fn raw<T: ?Sized>(ptr: *const T) -> Self::Raw<T>;
+}
+
+///
+/// Thread safety of the wrapped pointer, implemented by [`SendSync`] and [`SendOnly`].
+///
+pub trait ThreadSafety: sealed::Sealed + 'static {
+    /// Prefix of the type name used in `Debug` output.
+    const NAME: &'static str;
+}
+
+///
+/// Whether the wrapper implements `Deref` to the raw pointer, implemented by [`AutoDeref`] and [`NoDeref`].
+///
+pub trait DerefMode: sealed::Sealed + 'static {}
+
+///
+/// The wrapped pointer is a `*mut T` and can be written through.
+///
+pub enum Mut {}
+
+///
+/// The wrapped pointer is a `*const T` and can only be read through.
+///
+pub enum Const {}
+
+///
+/// The wrapped pointer is Send+Sync.
+///
+pub enum SendSync {}
+
+///
+/// The wrapped pointer is Send but not Sync.
+///
+pub enum SendOnly {}
+
+///
+/// The wrapper implements `Deref` to the raw pointer, so every raw pointer method can be called on it.
+///
+/// This is the default.
+///
+pub enum AutoDeref {}
+
+///
+/// The wrapper does not implement `Deref`, only its inherent methods can be called on it.
+///
+/// Use this through the aliases in [`explicit`](crate::explicit).
+///
+pub enum NoDeref {}
+
+impl sealed::Sealed for Mut {}
+impl sealed::Sealed for Const {}
+impl sealed::Sealed for SendSync {}
+impl sealed::Sealed for SendOnly {}
+impl sealed::Sealed for AutoDeref {}
+impl sealed::Sealed for NoDeref {}
+
+impl Mutability for Mut {
+    type Raw<T: ?Sized> = *mut T;
+
+    const NAME: &'static str = "Mut";
+
+    #[inline(always)]
+    fn raw<T: ?Sized>(ptr: *const T) -> *mut T {
+        ptr.cast_mut()
+    }
+}
+
+impl Mutability for Const {
+    type Raw<T: ?Sized> = *const T;
+
+    const NAME: &'static str = "Const";
+
+    #[inline(always)]
+    fn raw<T: ?Sized>(ptr: *const T) -> *const T {
+        ptr
+    }
+}
+
+impl ThreadSafety for SendSync {
+    const NAME: &'static str = "Sync";
+}
+
+impl ThreadSafety for SendOnly {
+    const NAME: &'static str = "Send";
+}
+
+impl DerefMode for AutoDeref {}
+
+impl DerefMode for NoDeref {}
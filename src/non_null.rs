@@ -0,0 +1,344 @@
+//! Non-null counterparts of the wrapper types.
+//!
+//! These wrap a [`NonNull`] instead of a raw pointer, so `Option<SyncMutNonNull<T>>`
+//! has the same size as `*mut T` and `None` can be used to encode a missing handle.
+//!
+//! Unlike the nullable wrappers these are four separate types rather than aliases of `Ptr`.
+//! All four are generated by `non_null_impl!`, so they only have the methods defined there
+//! and the traits `PtrWrapper`, `SendPtr`, `SyncPtr` and `MutPtr`. They have no `Tag` parameter,
+//! no `NoDeref` variant, no pointer arithmetic that keeps the wrapper, no `TryFrom`, no bulk slice
+//! or `Vec` conversions and cannot be compared with other wrapper types. Convert to the nullable
+//! counterpart with `as_nullable` or `From` where one of those is needed.
+//!
+//! Like the nullable wrappers the mutable ones are invariant in `T`, while the const ones
+//! are covariant like `NonNull`.
+
+use crate::{SendConstPtr, SendMutPtr, SyncConstPtr, SyncMutPtr};
+use core::fmt::{Formatter, Pointer};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::NonNull;
+
+/// Implements the non-null wrapper `$SelfType` of a `*$raw T` whose nullable counterpart is `$Nullable`.
+///
+/// `$thread` is `sync` or `send`, `$Name` is the matching name used in the docs and `$handle`
+/// finishes the safety sentence of the constructors. `$OtherMut` and `$OtherThread` are the wrappers
+/// with the other mutability and the other thread safety.
+macro_rules! non_null_impl {
+    (
+        $(#[$attr:meta])*
+        $SelfType:ident, $Nullable:ident, $raw:tt, $thread:tt, $Name:literal, $handle:literal,
+        $OtherMut:ident, $OtherThread:ident
+    ) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        pub struct $SelfType<T: ?Sized>(NonNull<T>, PhantomData<*$raw T>);
+
+        unsafe impl<T: ?Sized> Send for $SelfType<T> {}
+        non_null_impl!(@sync $thread $SelfType);
+
+        trait_impl!($SelfType);
+
+        impl<T: ?Sized> $SelfType<T> {
+            ///
+            #[doc = concat!("Makes `ptr` ", $Name)]
+            ///
+            /// # Safety
+            #[doc = concat!("The `ptr` parameter must be able to handle ", $handle)]
+            /// or special care must be taken when using the wrapped `ptr` to not use it
+            /// in any way in other threads.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const unsafe fn new(ptr: NonNull<T>) -> Self {
+                Self(ptr, PhantomData)
+            }
+
+            ///
+            #[doc = concat!("Makes `ptr` ", $Name, " if it is not null.")]
+            ///
+            /// # Safety
+            #[doc = concat!("The `ptr` parameter must be able to handle ", $handle)]
+            /// or special care must be taken when using the wrapped `ptr` to not use it
+            /// in any way in other threads.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const unsafe fn new_checked(ptr: *$raw T) -> Option<Self> {
+                match NonNull::new(non_null_impl!(@to_mut $raw ptr)) {
+                    Some(ptr) => Some(Self(ptr, PhantomData)),
+                    None => None,
+                }
+            }
+
+            ///
+            #[doc = concat!("Makes `ptr` ", $Name, " without checking if it is null.")]
+            ///
+            /// # Safety
+            /// `ptr` must not be null.
+            ///
+            #[doc = concat!("The `ptr` parameter must be able to handle ", $handle)]
+            /// or special care must be taken when using the wrapped `ptr` to not use it
+            /// in any way in other threads.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const unsafe fn new_unchecked(ptr: *$raw T) -> Self {
+                Self(NonNull::new_unchecked(non_null_impl!(@to_mut $raw ptr)), PhantomData)
+            }
+
+            ///
+            /// Converts a nullable `ptr` into its non-null counterpart, returns `None` if `ptr` is null.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn from_nullable(ptr: $Nullable<T>) -> Option<Self> {
+                match NonNull::new(non_null_impl!(@to_mut $raw ptr.inner())) {
+                    Some(ptr) => Some(Self(ptr, PhantomData)),
+                    None => None,
+                }
+            }
+
+            ///
+            #[doc = concat!("Converts `ptr` into its nullable counterpart while keeping it ", $Name, ".")]
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn as_nullable(&self) -> $Nullable<T> {
+                $Nullable::wrap(self.0.as_ptr())
+            }
+
+            ///
+            #[doc = concat!("Casts `ptr` to another data type while keeping it ", $Name, ".")]
+            ///
+            /// Pointer metadata (slice length, vtable) is discarded because `Y` is always a sized type.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn cast<Y>(&self) -> $SelfType<Y> {
+                $SelfType(self.0.cast(), PhantomData)
+            }
+
+            ///
+            #[doc = concat!("Returns inner `ptr` which is then no longer ", $Name, ".")]
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn inner(&self) -> NonNull<T> {
+                self.0
+            }
+
+            ///
+            #[doc = concat!("Returns inner `ptr` as a raw pointer which is then no longer ", $Name, ".")]
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn as_ptr(&self) -> *$raw T {
+                non_null_impl!(@from_mut $raw self.0.as_ptr())
+            }
+
+            non_null_impl!(@into_mutability $raw $OtherMut);
+            non_null_impl!(@into_thread $thread $OtherThread);
+        }
+
+        impl<T> $SelfType<T> {
+            ///
+            #[doc = concat!("Makes a ", $Name, " dangling ptr that is well aligned but not valid for reads or writes.")]
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn dangling() -> Self {
+                Self(NonNull::dangling(), PhantomData)
+            }
+        }
+
+        impl<T> $SelfType<[T]> {
+            ///
+            #[doc = concat!("Creates a ", $Name, " slice pointer from a ", $Name, " pointer to the first element and a length.")]
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn slice_from_raw_parts(data: $SelfType<T>, len: usize) -> Self {
+                Self(NonNull::slice_from_raw_parts(data.0, len), PhantomData)
+            }
+
+            ///
+            /// Returns the length of the slice `ptr` points to.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            ///
+            /// Returns true if the slice `ptr` points to has a length of 0.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+        }
+
+        impl<T: ?Sized> Deref for $SelfType<T> {
+            type Target = NonNull<T>;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<T: ?Sized> From<$SelfType<T>> for NonNull<T> {
+            #[inline(always)]
+            fn from(val: $SelfType<T>) -> Self {
+                val.inner()
+            }
+        }
+
+        impl<T: ?Sized> From<$SelfType<T>> for $Nullable<T> {
+            #[inline(always)]
+            fn from(val: $SelfType<T>) -> Self {
+                val.as_nullable()
+            }
+        }
+
+        impl<T: ?Sized> From<$SelfType<T>> for *const T {
+            #[inline(always)]
+            fn from(val: $SelfType<T>) -> Self {
+                val.as_ptr()
+            }
+        }
+
+        non_null_impl!(@from_impl $raw $SelfType);
+    };
+
+    (@sync sync $SelfType:ident) => {
+        unsafe impl<T: ?Sized> Sync for $SelfType<T> {}
+    };
+    (@sync send $SelfType:ident) => {};
+
+    (@to_mut mut $ptr:expr) => {
+        $ptr
+    };
+    (@to_mut const $ptr:expr) => {
+        $ptr.cast_mut()
+    };
+
+    (@from_mut mut $ptr:expr) => {
+        $ptr
+    };
+    (@from_mut const $ptr:expr) => {
+        $ptr.cast_const()
+    };
+
+    (@from_impl mut $SelfType:ident) => {
+        impl<T: ?Sized> From<$SelfType<T>> for *mut T {
+            #[inline(always)]
+            fn from(val: $SelfType<T>) -> Self {
+                val.as_ptr()
+            }
+        }
+    };
+    (@from_impl const $SelfType:ident) => {};
+
+    (@into_mutability mut $Other:ident) => {
+        ///
+        /// Makes `ptr` immutable while keeping its thread safety.
+        ///
+        /// This is a downgrade and therefore safe.
+        ///
+        #[inline(always)]
+        #[must_use]
+        pub const fn into_const(self) -> $Other<T> {
+            $Other(self.0, PhantomData)
+        }
+    };
+    (@into_mutability const $Other:ident) => {
+        ///
+        /// Makes `ptr` mutable while keeping its thread safety.
+        ///
+        /// # Safety
+        /// Writing through the returned pointer to data that must not be mutated, for example
+        /// data behind a shared reference, is UB.
+        ///
+        #[inline(always)]
+        #[must_use]
+        pub const unsafe fn into_mut(self) -> $Other<T> {
+            $Other(self.0, PhantomData)
+        }
+    };
+
+    (@into_thread sync $Other:ident) => {
+        ///
+        /// Makes `ptr` no longer Sync while keeping its mutability.
+        ///
+        /// This is a downgrade and therefore safe.
+        ///
+        #[inline(always)]
+        #[must_use]
+        pub const fn into_send(self) -> $Other<T> {
+            $Other(self.0, PhantomData)
+        }
+    };
+    (@into_thread send $Other:ident) => {
+        ///
+        /// Makes `ptr` Sync while keeping its mutability.
+        ///
+        /// # Safety
+        /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
+        /// or special care must be taken when using the wrapped `ptr` to not use it
+        /// in any way in other threads.
+        ///
+        #[inline(always)]
+        #[must_use]
+        pub const unsafe fn into_sync(self) -> $Other<T> {
+            $Other(self.0, PhantomData)
+        }
+    };
+}
+
+non_null_impl!(
+    ///
+    /// Wrapped non-null mutable raw pointer that is Send+Sync
+    ///
+    /// `Option<SyncMutNonNull<T>>` has the same size as `*mut T`.
+    ///
+    SyncMutNonNull, SyncMutPtr, mut, sync, "Send+Sync",
+    "being sent and used in other threads concurrently,",
+    SyncConstNonNull, SendMutNonNull
+);
+
+non_null_impl!(
+    ///
+    /// Wrapped non-null const raw pointer that is Send+Sync
+    ///
+    /// `Option<SyncConstNonNull<T>>` has the same size as `*const T`.
+    ///
+    SyncConstNonNull, SyncConstPtr, const, sync, "Send+Sync",
+    "being sent and used in other threads concurrently,",
+    SyncMutNonNull, SendConstNonNull
+);
+
+non_null_impl!(
+    ///
+    /// Wrapped non-null mutable raw pointer that is Send but not Sync
+    ///
+    /// `Option<SendMutNonNull<T>>` has the same size as `*mut T`.
+    ///
+    SendMutNonNull, SendMutPtr, mut, send, "Send",
+    "being sent to other threads",
+    SendConstNonNull, SyncMutNonNull
+);
+
+non_null_impl!(
+    ///
+    /// Wrapped non-null const raw pointer that is Send but not Sync
+    ///
+    /// `Option<SendConstNonNull<T>>` has the same size as `*const T`.
+    ///
+    SendConstNonNull, SendConstPtr, const, send, "Send",
+    "being sent to other threads",
+    SendMutNonNull, SyncConstNonNull
+);
@@ -0,0 +1,220 @@
+//! Owning wrappers that release the pointee with a deleter when they are dropped.
+//!
+//! The deleter is either a function pointer such as `unsafe extern "C" fn(*mut T)`
+//! or a type that implements [`Deleter`], which costs no space if it is zero-sized:
+//!
+//! ```
+//! use sync_ptr::{Deleter, SendOwnedPtr};
+//!
+//! struct FreeBuffer;
+//!
+//! impl Deleter<u64> for FreeBuffer {
+//!     unsafe fn delete(&mut self, ptr: *mut u64) {
+//!         drop(Box::from_raw(ptr));
+//!     }
+//! }
+//!
+//! let buffer = unsafe { SendOwnedPtr::new(Box::into_raw(Box::new(7u64)), FreeBuffer) };
+//! assert_eq!(size_of_val(&buffer), size_of::<*mut u64>());
+//!
+//! let value = std::thread::spawn(move || unsafe { buffer.as_const_ptr().read() })
+//!     .join()
+//!     .unwrap();
+//! assert_eq!(value, 7);
+//! ```
+
+use crate::{SendConstPtr, SendMutPtr, SyncConstPtr, SyncMutPtr};
+use core::mem::ManuallyDrop;
+
+///
+/// Releases the pointee of an owning wrapper.
+///
+/// Implemented for `fn(*mut T)`, `extern "C" fn(*mut T)` and their unsafe variants.
+///
+pub trait Deleter<T: ?Sized> {
+    ///
+    /// Releases the pointee of `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` is the non-null pointer the owning wrapper was made with,
+    /// and it is released only once.
+    ///
+    unsafe fn delete(&mut self, ptr: *mut T);
+}
+
+impl<T: ?Sized> Deleter<T> for fn(*mut T) {
+    #[inline(always)]
+    unsafe fn delete(&mut self, ptr: *mut T) {
+        self(ptr);
+    }
+}
+
+impl<T: ?Sized> Deleter<T> for unsafe fn(*mut T) {
+    #[inline(always)]
+    unsafe fn delete(&mut self, ptr: *mut T) {
+        self(ptr);
+    }
+}
+
+impl<T: ?Sized> Deleter<T> for extern "C" fn(*mut T) {
+    #[inline(always)]
+    unsafe fn delete(&mut self, ptr: *mut T) {
+        self(ptr);
+    }
+}
+
+impl<T: ?Sized> Deleter<T> for unsafe extern "C" fn(*mut T) {
+    #[inline(always)]
+    unsafe fn delete(&mut self, ptr: *mut T) {
+        self(ptr);
+    }
+}
+
+/// Implements the owning wrapper `$SelfType` around the wrapper `$Mut`, with `$Const` as its const view.
+macro_rules! owned_impl {
+    ($SelfType:ident, $Mut:ident, $Const:ident) => {
+        impl<T: ?Sized, D: Deleter<T>> $SelfType<T, D> {
+            ///
+            /// Takes ownership of `ptr`, which is released by `deleter` when the wrapper is dropped.
+            /// A null `ptr` is never passed to `deleter`.
+            ///
+            /// # Safety
+            /// The pointee must be able to handle being released by `deleter` in another thread,
+            /// and `ptr` must be usable like in the `new` function of
+            #[doc = concat!("`", stringify!($Mut), "`.")]
+            /// No one else may release `ptr`.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const unsafe fn new(ptr: *mut T, deleter: D) -> Self {
+                Self {
+                    ptr: $Mut::new(ptr),
+                    deleter,
+                }
+            }
+
+            ///
+            /// Returns true if the owned pointer is null.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn is_null(&self) -> bool {
+                self.ptr.is_null()
+            }
+
+            ///
+            /// Returns the owned pointer, the wrapper keeps ownership.
+            ///
+            /// The returned pointer must not be used after the wrapper was dropped.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn as_ptr(&self) -> $Mut<T> {
+                self.ptr
+            }
+
+            ///
+            /// Returns the owned pointer as immutable, the wrapper keeps ownership.
+            ///
+            /// The returned pointer must not be used after the wrapper was dropped.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn as_const_ptr(&self) -> $Const<T> {
+                self.ptr.into_const()
+            }
+
+            ///
+            /// Returns inner `ptr` which is then no longer Send or Sync, the wrapper keeps ownership.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn inner(&self) -> *mut T {
+                self.ptr.inner()
+            }
+
+            ///
+            /// Returns the deleter.
+            ///
+            #[inline(always)]
+            #[must_use]
+            pub const fn deleter(&self) -> &D {
+                &self.deleter
+            }
+
+            ///
+            /// Gives up ownership and returns inner `ptr` without releasing it.
+            /// The deleter is dropped.
+            ///
+            #[inline(always)]
+            #[must_use = "losing the pointer will leak the pointee"]
+            pub fn into_raw(self) -> *mut T {
+                self.leak().inner()
+            }
+
+            ///
+            /// Gives up ownership and returns the wrapped pointer without releasing it.
+            /// The deleter is dropped.
+            ///
+            #[inline(always)]
+            #[must_use = "losing the pointer will leak the pointee"]
+            pub fn leak(self) -> $Mut<T> {
+                let mut this = ManuallyDrop::new(self);
+                // SAFETY: `this` is never used or dropped again, so the deleter is dropped exactly once.
+                unsafe { core::ptr::drop_in_place(&raw mut this.deleter) };
+                this.ptr
+            }
+        }
+
+        impl<T: ?Sized, D: Deleter<T>> Drop for $SelfType<T, D> {
+            fn drop(&mut self) {
+                if !self.ptr.is_null() {
+                    // SAFETY: `new` transferred ownership of the non-null `ptr`, and this runs once.
+                    unsafe { self.deleter.delete(self.ptr.inner()) };
+                }
+            }
+        }
+
+        impl<T: ?Sized, D: Deleter<T>> core::fmt::Pointer for $SelfType<T, D> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Pointer::fmt(&self.ptr, f)
+            }
+        }
+
+        impl<T: ?Sized, D: Deleter<T>> core::fmt::Debug for $SelfType<T, D> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($SelfType))
+                    .field(&self.ptr.inner())
+                    .finish()
+            }
+        }
+    };
+}
+
+///
+/// Owning mutable raw pointer that is Send but not Sync, released by `D` when dropped.
+///
+/// The wrapper is Send if `D` is Send.
+///
+pub struct SendOwnedPtr<T: ?Sized, D: Deleter<T>> {
+    /// The owned pointer.
+    ptr: SendMutPtr<T>,
+    /// Releases `ptr` on drop.
+    deleter: D,
+}
+
+owned_impl!(SendOwnedPtr, SendMutPtr, SendConstPtr);
+
+///
+/// Owning mutable raw pointer that is Send+Sync, released by `D` when dropped.
+///
+/// The wrapper is Send if `D` is Send, and Sync if `D` is Sync.
+///
+pub struct SyncOwnedPtr<T: ?Sized, D: Deleter<T>> {
+    /// The owned pointer.
+    ptr: SyncMutPtr<T>,
+    /// Releases `ptr` on drop.
+    deleter: D,
+}
+
+owned_impl!(SyncOwnedPtr, SyncMutPtr, SyncConstPtr);
@@ -0,0 +1,654 @@
+//! Slice pointer wrappers that carry their length and can be split into disjoint parts.
+
+use crate::{SendConstPtr, SendMutPtr, SyncConstPtr};
+use core::fmt::{Formatter, Pointer};
+use core::iter::FusedIterator;
+
+///
+/// Wrapped mutable raw slice pointer that is Send but not Sync and not Copy.
+///
+/// Because this type cannot be copied, the sub slices returned by `split_at`, `chunks` and `chunks_exact`
+/// are guaranteed to be disjoint, each of them can be sent to a different worker thread.
+///
+#[repr(transparent)]
+pub struct SendMutSlicePtr<T>(SendMutPtr<[T]>);
+
+impl<T> SendMutSlicePtr<T> {
+    ///
+    /// Makes `ptr` Send.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    /// No other `SendMutSlicePtr` may point to memory that overlaps with `ptr`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new(ptr: *mut [T]) -> Self {
+        Self(SendMutPtr::wrap(ptr))
+    }
+
+    ///
+    /// Makes the slice made from `data` and `len` Send.
+    ///
+    /// # Safety
+    /// Same as `new`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw_parts(data: *mut T, len: usize) -> Self {
+        Self::new(core::ptr::slice_from_raw_parts_mut(data, len))
+    }
+
+    ///
+    /// Returns the number of elements in the slice.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    ///
+    /// Returns true if the slice has a length of 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *mut [T] {
+        self.0.inner()
+    }
+
+    ///
+    /// Returns a Send pointer to the first element of the slice.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(&self) -> SendMutPtr<T> {
+        self.0.cast()
+    }
+
+    ///
+    /// Returns the wrapped slice pointer, which is Copy and therefore no longer guaranteed to be disjoint.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_send_mut(self) -> SendMutPtr<[T]> {
+        self.0
+    }
+
+    ///
+    /// Returns the wrapped slice pointer as immutable.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_send_const(self) -> SendConstPtr<[T]> {
+        self.0.into_const()
+    }
+
+    ///
+    /// Returns a Send pointer to the element at `index` or `None` if `index` is out of bounds.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(&self, index: usize) -> Option<SendMutPtr<T>> {
+        if index < self.len() {
+            Some(SendMutPtr::wrap(
+                self.0.cast::<T>().inner().wrapping_add(index),
+            ))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Splits the slice into two disjoint slices at `mid`.
+    ///
+    /// The first slice contains the elements `[0, mid)`, the second slice contains the elements `[mid, len)`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn split_at(self, mid: usize) -> (Self, Self) {
+        let mut rest = self;
+        let first = rest.split_off_front(mid);
+        (first, rest)
+    }
+
+    ///
+    /// Removes the first `mid` elements from this slice and returns them as a separate slice.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    const fn split_off_front(&mut self, mid: usize) -> Self {
+        let len = self.len();
+        assert!(mid <= len, "mid > len");
+        let data = self.0.cast::<T>().inner();
+        self.0 = SendMutPtr::wrap(core::ptr::slice_from_raw_parts_mut(
+            data.wrapping_add(mid),
+            len - mid,
+        ));
+        Self(SendMutPtr::wrap(core::ptr::slice_from_raw_parts_mut(
+            data, mid,
+        )))
+    }
+
+    ///
+    /// Returns an iterator over disjoint chunks of `chunk_size` elements.
+    /// The last chunk will be shorter if `chunk_size` does not divide the length of the slice.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn chunks(self, chunk_size: usize) -> SendMutChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        SendMutChunks {
+            rest: self,
+            chunk_size,
+        }
+    }
+
+    ///
+    /// Returns an iterator over disjoint chunks of exactly `chunk_size` elements.
+    /// The elements that do not fit into a chunk can be obtained with `into_remainder`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn chunks_exact(self, chunk_size: usize) -> SendMutChunksExact<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let exact = self.len() - self.len() % chunk_size;
+        let (rest, remainder) = self.split_at(exact);
+        SendMutChunksExact {
+            rest,
+            remainder,
+            chunk_size,
+        }
+    }
+
+    ///
+    /// Returns a shared reference to the slice.
+    ///
+    /// # Safety
+    /// The slice must be valid for reads for the entire lifetime `'a`
+    /// and must not be mutated by anything else during that lifetime.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_slice<'a>(&self) -> &'a [T] {
+        &*self.0.inner()
+    }
+
+    ///
+    /// Returns a mutable reference to the slice.
+    ///
+    /// # Safety
+    /// The slice must be valid for reads and writes for the entire lifetime `'a`
+    /// and must not be accessed by anything else during that lifetime.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_mut_slice<'a>(&mut self) -> &'a mut [T] {
+        &mut *self.0.inner()
+    }
+}
+
+impl<T> Pointer for SendMutSlicePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T> core::fmt::Debug for SendMutSlicePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SendMutSlicePtr")
+            .field("ptr", &self.0.cast::<T>().inner())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T> From<SendMutSlicePtr<T>> for SendMutPtr<[T]> {
+    #[inline(always)]
+    fn from(val: SendMutSlicePtr<T>) -> Self {
+        val.into_send_mut()
+    }
+}
+
+///
+/// Iterator over disjoint chunks of a `SendMutSlicePtr`, created by `SendMutSlicePtr::chunks`.
+///
+pub struct SendMutChunks<T> {
+    /// The part of the slice that was not yet returned.
+    rest: SendMutSlicePtr<T>,
+    /// Maximum number of elements per chunk.
+    chunk_size: usize,
+}
+
+impl<T> Iterator for SendMutChunks<T> {
+    type Item = SendMutSlicePtr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self.chunk_size.min(self.rest.len());
+        Some(self.rest.split_off_front(mid))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.rest.len().div_ceil(self.chunk_size);
+        (count, Some(count))
+    }
+}
+
+impl<T> ExactSizeIterator for SendMutChunks<T> {}
+impl<T> FusedIterator for SendMutChunks<T> {}
+
+impl<T> core::fmt::Debug for SendMutChunks<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SendMutChunks")
+            .field("rest", &self.rest)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+///
+/// Iterator over disjoint chunks of exactly `chunk_size` elements of a `SendMutSlicePtr`,
+/// created by `SendMutSlicePtr::chunks_exact`.
+///
+pub struct SendMutChunksExact<T> {
+    /// The part of the slice that was not yet returned, its length is a multiple of `chunk_size`.
+    rest: SendMutSlicePtr<T>,
+    /// The elements that do not fit into a chunk.
+    remainder: SendMutSlicePtr<T>,
+    /// Number of elements per chunk.
+    chunk_size: usize,
+}
+
+impl<T> SendMutChunksExact<T> {
+    ///
+    /// Returns the elements that do not fit into a chunk, this is disjoint from all returned chunks.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_remainder(self) -> SendMutSlicePtr<T> {
+        self.remainder
+    }
+}
+
+impl<T> Iterator for SendMutChunksExact<T> {
+    type Item = SendMutSlicePtr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        Some(self.rest.split_off_front(self.chunk_size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.rest.len() / self.chunk_size;
+        (count, Some(count))
+    }
+}
+
+impl<T> ExactSizeIterator for SendMutChunksExact<T> {}
+impl<T> FusedIterator for SendMutChunksExact<T> {}
+
+impl<T> core::fmt::Debug for SendMutChunksExact<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SendMutChunksExact")
+            .field("rest", &self.rest)
+            .field("remainder", &self.remainder)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+///
+/// Wrapped const raw slice pointer that is Send+Sync.
+///
+/// Because the slice is only read, the sub slices returned by `split_at`, `chunks` and `chunks_exact`
+/// can be shared with any number of worker threads.
+///
+#[repr(transparent)]
+pub struct SyncConstSlicePtr<T>(SyncConstPtr<[T]>);
+
+impl<T> Clone for SyncConstSlicePtr<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SyncConstSlicePtr<T> {}
+
+impl<T> SyncConstSlicePtr<T> {
+    ///
+    /// Makes `ptr` Send+Sync.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent and used in other threads concurrently,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new(ptr: *const [T]) -> Self {
+        Self(SyncConstPtr::wrap(ptr))
+    }
+
+    ///
+    /// Makes the slice made from `data` and `len` Send+Sync.
+    ///
+    /// # Safety
+    /// Same as `new`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw_parts(data: *const T, len: usize) -> Self {
+        Self::new(core::ptr::slice_from_raw_parts(data, len))
+    }
+
+    ///
+    /// Returns the number of elements in the slice.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    ///
+    /// Returns true if the slice has a length of 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send+Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *const [T] {
+        self.0.inner()
+    }
+
+    ///
+    /// Returns a Send+Sync pointer to the first element of the slice.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(&self) -> SyncConstPtr<T> {
+        self.0.cast()
+    }
+
+    ///
+    /// Returns the wrapped slice pointer.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_sync_const(self) -> SyncConstPtr<[T]> {
+        self.0
+    }
+
+    ///
+    /// Returns a Send+Sync pointer to the element at `index` or `None` if `index` is out of bounds.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(&self, index: usize) -> Option<SyncConstPtr<T>> {
+        if index < self.len() {
+            Some(SyncConstPtr::wrap(
+                self.0.cast::<T>().inner().wrapping_add(index),
+            ))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Splits the slice into two disjoint slices at `mid`.
+    ///
+    /// The first slice contains the elements `[0, mid)`, the second slice contains the elements `[mid, len)`.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn split_at(&self, mid: usize) -> (Self, Self) {
+        let len = self.len();
+        assert!(mid <= len, "mid > len");
+        let data = self.0.cast::<T>().inner();
+        (
+            Self(SyncConstPtr::wrap(core::ptr::slice_from_raw_parts(
+                data, mid,
+            ))),
+            Self(SyncConstPtr::wrap(core::ptr::slice_from_raw_parts(
+                data.wrapping_add(mid),
+                len - mid,
+            ))),
+        )
+    }
+
+    ///
+    /// Returns an iterator over disjoint chunks of `chunk_size` elements.
+    /// The last chunk will be shorter if `chunk_size` does not divide the length of the slice.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn chunks(&self, chunk_size: usize) -> SyncConstChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        SyncConstChunks {
+            rest: *self,
+            chunk_size,
+        }
+    }
+
+    ///
+    /// Returns an iterator over disjoint chunks of exactly `chunk_size` elements.
+    /// The elements that do not fit into a chunk can be obtained with `remainder`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn chunks_exact(&self, chunk_size: usize) -> SyncConstChunksExact<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let (rest, remainder) = self.split_at(self.len() - self.len() % chunk_size);
+        SyncConstChunksExact {
+            rest,
+            remainder,
+            chunk_size,
+        }
+    }
+
+    ///
+    /// Returns a shared reference to the slice.
+    ///
+    /// # Safety
+    /// The slice must be valid for reads for the entire lifetime `'a`
+    /// and must not be mutated by anything during that lifetime.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_slice<'a>(&self) -> &'a [T] {
+        &*self.0.inner()
+    }
+}
+
+impl<T> Pointer for SyncConstSlicePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T> core::fmt::Debug for SyncConstSlicePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SyncConstSlicePtr")
+            .field("ptr", &self.0.cast::<T>().inner())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T> Eq for SyncConstSlicePtr<T> {}
+impl<T> PartialEq for SyncConstSlicePtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&self.0, &other.0)
+    }
+}
+
+impl<T> core::hash::Hash for SyncConstSlicePtr<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.0, state);
+    }
+}
+
+impl<T> From<SyncConstSlicePtr<T>> for SyncConstPtr<[T]> {
+    #[inline(always)]
+    fn from(val: SyncConstSlicePtr<T>) -> Self {
+        val.into_sync_const()
+    }
+}
+
+///
+/// Iterator over chunks of a `SyncConstSlicePtr`, created by `SyncConstSlicePtr::chunks`.
+///
+pub struct SyncConstChunks<T> {
+    /// The part of the slice that was not yet returned.
+    rest: SyncConstSlicePtr<T>,
+    /// Maximum number of elements per chunk.
+    chunk_size: usize,
+}
+
+impl<T> Iterator for SyncConstChunks<T> {
+    type Item = SyncConstSlicePtr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.rest.split_at(self.chunk_size.min(self.rest.len()));
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.rest.len().div_ceil(self.chunk_size);
+        (count, Some(count))
+    }
+}
+
+impl<T> ExactSizeIterator for SyncConstChunks<T> {}
+impl<T> FusedIterator for SyncConstChunks<T> {}
+
+impl<T> Clone for SyncConstChunks<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for SyncConstChunks<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SyncConstChunks")
+            .field("rest", &self.rest)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+///
+/// Iterator over chunks of exactly `chunk_size` elements of a `SyncConstSlicePtr`,
+/// created by `SyncConstSlicePtr::chunks_exact`.
+///
+pub struct SyncConstChunksExact<T> {
+    /// The part of the slice that was not yet returned, its length is a multiple of `chunk_size`.
+    rest: SyncConstSlicePtr<T>,
+    /// The elements that do not fit into a chunk.
+    remainder: SyncConstSlicePtr<T>,
+    /// Number of elements per chunk.
+    chunk_size: usize,
+}
+
+impl<T> SyncConstChunksExact<T> {
+    ///
+    /// Returns the elements that do not fit into a chunk.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn remainder(&self) -> SyncConstSlicePtr<T> {
+        self.remainder
+    }
+}
+
+impl<T> Iterator for SyncConstChunksExact<T> {
+    type Item = SyncConstSlicePtr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.rest.split_at(self.chunk_size);
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.rest.len() / self.chunk_size;
+        (count, Some(count))
+    }
+}
+
+impl<T> ExactSizeIterator for SyncConstChunksExact<T> {}
+impl<T> FusedIterator for SyncConstChunksExact<T> {}
+
+impl<T> Clone for SyncConstChunksExact<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest,
+            remainder: self.remainder,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for SyncConstChunksExact<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SyncConstChunksExact")
+            .field("rest", &self.rest)
+            .field("remainder", &self.remainder)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
@@ -0,0 +1,438 @@
+//! Tagged pointer wrappers that store a small tag in the unused low alignment bits of the pointer.
+
+use crate::{SendMutPtr, SyncMutPtr};
+use core::fmt::{Formatter, Pointer};
+
+///
+/// Wrapped mutable raw pointer that is Send+Sync and stores a `BITS` wide tag in its low bits.
+///
+/// `BITS` must not exceed the number of low bits that are always zero for a pointer aligned for `T`.
+/// This is checked at compile time, for example `SyncTaggedPtr<u32, 3>` fails to compile because `u32`
+/// is only aligned to 4 bytes, which leaves room for 2 tag bits.
+///
+/// ```compile_fail
+/// let _ = sync_ptr::SyncTaggedPtr::<u32, 3>::null();
+/// ```
+///
+#[repr(transparent)]
+pub struct SyncTaggedPtr<T, const BITS: u32>(*mut T);
+
+unsafe impl<T, const BITS: u32> Sync for SyncTaggedPtr<T, BITS> {}
+unsafe impl<T, const BITS: u32> Send for SyncTaggedPtr<T, BITS> {}
+
+impl<T, const BITS: u32> SyncTaggedPtr<T, BITS> {
+    /// Compile time check that `BITS` tag bits fit into the alignment of `T`.
+    const BITS_FIT: () = assert!(
+        BITS <= align_of::<T>().trailing_zeros(),
+        "the alignment of T does not leave room for BITS tag bits"
+    );
+
+    ///
+    /// Mask of the bits that store the tag.
+    ///
+    pub const TAG_MASK: usize = {
+        let () = Self::BITS_FIT;
+        (1 << BITS) - 1
+    };
+
+    ///
+    /// Combines the Send+Sync `ptr` with `tag`.
+    ///
+    /// # Panics
+    /// Panics if the low `BITS` bits of `ptr` are not zero or if `tag` does not fit into `BITS` bits.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn new(ptr: SyncMutPtr<T>, tag: usize) -> Self {
+        assert_eq!(
+            ptr.0.addr() & Self::TAG_MASK,
+            0,
+            "ptr is not sufficiently aligned to store a tag"
+        );
+        assert!(tag <= Self::TAG_MASK, "tag does not fit into BITS bits");
+        Self(ptr.inner().map_addr(|addr| addr | tag))
+    }
+
+    ///
+    /// Makes a Send+Sync null ptr with a tag of 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn null() -> Self {
+        let () = Self::BITS_FIT;
+        Self(core::ptr::null_mut())
+    }
+
+    ///
+    /// Makes the already tagged `ptr` Send+Sync.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent and used in other threads concurrently,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw(ptr: *mut T) -> Self {
+        let () = Self::BITS_FIT;
+        Self(ptr)
+    }
+
+    ///
+    /// Returns the inner tagged `ptr` which is then no longer Send+Sync.
+    ///
+    /// The returned pointer must not be dereferenced before the tag is removed.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_raw(self) -> *mut T {
+        self.0
+    }
+
+    ///
+    /// Returns the pointer without the tag while keeping it Send+Sync.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn ptr(&self) -> SyncMutPtr<T> {
+        SyncMutPtr::wrap(self.0.map_addr(|addr| addr & !Self::TAG_MASK))
+    }
+
+    ///
+    /// Returns the tag.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn tag(&self) -> usize {
+        self.0.addr() & Self::TAG_MASK
+    }
+
+    ///
+    /// Returns true if the pointer without the tag is null.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        self.ptr().0.is_null()
+    }
+
+    ///
+    /// Returns a copy of this `ptr` with the tag replaced by `tag`.
+    ///
+    /// # Panics
+    /// Panics if `tag` does not fit into `BITS` bits.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn with_tag(&self, tag: usize) -> Self {
+        Self::new(self.ptr(), tag)
+    }
+
+    ///
+    /// Returns a copy of `ptr` with the tag of this `ptr`.
+    ///
+    /// # Panics
+    /// Panics if the low `BITS` bits of `ptr` are not zero.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn with_ptr(&self, ptr: SyncMutPtr<T>) -> Self {
+        Self::new(ptr, self.tag())
+    }
+
+    ///
+    /// Returns a copy of this `ptr` with the tag replaced by the result of `f`.
+    ///
+    /// # Panics
+    /// Panics if the new tag does not fit into `BITS` bits.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn map_tag(&self, f: impl FnOnce(usize) -> usize) -> Self {
+        self.with_tag(f(self.tag()))
+    }
+
+    ///
+    /// Returns a copy of this `ptr` with the pointer replaced by the result of `f`, the tag is kept.
+    ///
+    /// # Panics
+    /// Panics if the low `BITS` bits of the new pointer are not zero.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn map_ptr(&self, f: impl FnOnce(SyncMutPtr<T>) -> SyncMutPtr<T>) -> Self {
+        self.with_ptr(f(self.ptr()))
+    }
+
+    ///
+    /// Returns true if both pointers are the same when ignoring their tags.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.ptr() == other.ptr()
+    }
+
+    ///
+    /// Makes this `ptr` no longer Sync, the tag is kept.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_send_tagged(self) -> SendTaggedPtr<T, BITS> {
+        SendTaggedPtr(self.0)
+    }
+}
+
+impl<T, const BITS: u32> Clone for SyncTaggedPtr<T, BITS> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const BITS: u32> Copy for SyncTaggedPtr<T, BITS> {}
+
+impl<T, const BITS: u32> Pointer for SyncTaggedPtr<T, BITS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.ptr(), f)
+    }
+}
+
+impl<T, const BITS: u32> Eq for SyncTaggedPtr<T, BITS> {}
+impl<T, const BITS: u32> PartialEq for SyncTaggedPtr<T, BITS> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&self.0, &other.0)
+    }
+}
+
+impl<T, const BITS: u32> core::fmt::Debug for SyncTaggedPtr<T, BITS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SyncTaggedPtr")
+            .field("ptr", &self.ptr().0)
+            .field("tag", &self.tag())
+            .finish()
+    }
+}
+
+impl<T, const BITS: u32> core::hash::Hash for SyncTaggedPtr<T, BITS> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.0, state);
+    }
+}
+
+///
+/// Wrapped mutable raw pointer that is Send but not Sync and stores a `BITS` wide tag in its low bits.
+///
+/// `BITS` must not exceed the number of low bits that are always zero for a pointer aligned for `T`.
+/// This is checked at compile time, for example `SendTaggedPtr<u32, 3>` fails to compile because `u32`
+/// is only aligned to 4 bytes, which leaves room for 2 tag bits.
+///
+#[repr(transparent)]
+pub struct SendTaggedPtr<T, const BITS: u32>(*mut T);
+
+unsafe impl<T, const BITS: u32> Send for SendTaggedPtr<T, BITS> {}
+
+impl<T, const BITS: u32> SendTaggedPtr<T, BITS> {
+    /// Compile time check that `BITS` tag bits fit into the alignment of `T`.
+    const BITS_FIT: () = assert!(
+        BITS <= align_of::<T>().trailing_zeros(),
+        "the alignment of T does not leave room for BITS tag bits"
+    );
+
+    ///
+    /// Mask of the bits that store the tag.
+    ///
+    pub const TAG_MASK: usize = {
+        let () = Self::BITS_FIT;
+        (1 << BITS) - 1
+    };
+
+    ///
+    /// Combines the Send `ptr` with `tag`.
+    ///
+    /// # Panics
+    /// Panics if the low `BITS` bits of `ptr` are not zero or if `tag` does not fit into `BITS` bits.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn new(ptr: SendMutPtr<T>, tag: usize) -> Self {
+        assert_eq!(
+            ptr.0.addr() & Self::TAG_MASK,
+            0,
+            "ptr is not sufficiently aligned to store a tag"
+        );
+        assert!(tag <= Self::TAG_MASK, "tag does not fit into BITS bits");
+        Self(ptr.inner().map_addr(|addr| addr | tag))
+    }
+
+    ///
+    /// Makes a Send null ptr with a tag of 0.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn null() -> Self {
+        let () = Self::BITS_FIT;
+        Self(core::ptr::null_mut())
+    }
+
+    ///
+    /// Makes the already tagged `ptr` Send.
+    ///
+    /// # Safety
+    /// The `ptr` parameter must be able to handle being sent to other threads
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw(ptr: *mut T) -> Self {
+        let () = Self::BITS_FIT;
+        Self(ptr)
+    }
+
+    ///
+    /// Returns the inner tagged `ptr` which is then no longer Send.
+    ///
+    /// The returned pointer must not be dereferenced before the tag is removed.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_raw(self) -> *mut T {
+        self.0
+    }
+
+    ///
+    /// Returns the pointer without the tag while keeping it Send.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn ptr(&self) -> SendMutPtr<T> {
+        SendMutPtr::wrap(self.0.map_addr(|addr| addr & !Self::TAG_MASK))
+    }
+
+    ///
+    /// Returns the tag.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn tag(&self) -> usize {
+        self.0.addr() & Self::TAG_MASK
+    }
+
+    ///
+    /// Returns true if the pointer without the tag is null.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        self.ptr().0.is_null()
+    }
+
+    ///
+    /// Returns a copy of this `ptr` with the tag replaced by `tag`.
+    ///
+    /// # Panics
+    /// Panics if `tag` does not fit into `BITS` bits.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn with_tag(&self, tag: usize) -> Self {
+        Self::new(self.ptr(), tag)
+    }
+
+    ///
+    /// Returns a copy of `ptr` with the tag of this `ptr`.
+    ///
+    /// # Panics
+    /// Panics if the low `BITS` bits of `ptr` are not zero.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn with_ptr(&self, ptr: SendMutPtr<T>) -> Self {
+        Self::new(ptr, self.tag())
+    }
+
+    ///
+    /// Returns a copy of this `ptr` with the tag replaced by the result of `f`.
+    ///
+    /// # Panics
+    /// Panics if the new tag does not fit into `BITS` bits.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn map_tag(&self, f: impl FnOnce(usize) -> usize) -> Self {
+        self.with_tag(f(self.tag()))
+    }
+
+    ///
+    /// Returns a copy of this `ptr` with the pointer replaced by the result of `f`, the tag is kept.
+    ///
+    /// # Panics
+    /// Panics if the low `BITS` bits of the new pointer are not zero.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn map_ptr(&self, f: impl FnOnce(SendMutPtr<T>) -> SendMutPtr<T>) -> Self {
+        self.with_ptr(f(self.ptr()))
+    }
+
+    ///
+    /// Returns true if both pointers are the same when ignoring their tags.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.ptr() == other.ptr()
+    }
+
+    ///
+    /// Makes this `ptr` Sync, the tag is kept.
+    ///
+    /// # Safety
+    /// This `ptr` must be able to handle being accessed by multiple threads at the same time,
+    /// or special care must be taken when using the wrapped `ptr` to not use it
+    /// in any way in other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn into_sync_tagged(self) -> SyncTaggedPtr<T, BITS> {
+        SyncTaggedPtr(self.0)
+    }
+}
+
+impl<T, const BITS: u32> Clone for SendTaggedPtr<T, BITS> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const BITS: u32> Copy for SendTaggedPtr<T, BITS> {}
+
+impl<T, const BITS: u32> Pointer for SendTaggedPtr<T, BITS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.ptr(), f)
+    }
+}
+
+impl<T, const BITS: u32> Eq for SendTaggedPtr<T, BITS> {}
+impl<T, const BITS: u32> PartialEq for SendTaggedPtr<T, BITS> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&self.0, &other.0)
+    }
+}
+
+impl<T, const BITS: u32> core::fmt::Debug for SendTaggedPtr<T, BITS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SendTaggedPtr")
+            .field("ptr", &self.ptr().0)
+            .field("tag", &self.tag())
+            .finish()
+    }
+}
+
+impl<T, const BITS: u32> core::hash::Hash for SendTaggedPtr<T, BITS> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.0, state);
+    }
+}
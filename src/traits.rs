@@ -0,0 +1,259 @@
+//! Sealed traits that abstract over the wrapper types, so generic code can accept any suitable wrapper.
+//!
+//! ```
+//! use sync_ptr::*;
+//!
+//! fn submit<P: SendPtr<u64>>(p: P) -> u64 {
+//!     std::thread::scope(|s| s.spawn(move || unsafe { p.as_const_ptr().read() }).join())
+//!         .unwrap()
+//! }
+//!
+//! let value = 5u64;
+//! let ptr: *const u64 = &value;
+//! assert_eq!(submit(unsafe { ptr.as_sync_const() }), 5);
+//! assert_eq!(submit(unsafe { ptr.as_send_const() }), 5);
+//! ```
+
+use crate::marker::{DerefMode, Mut, Mutability, SendSync, ThreadSafety};
+use crate::{Ptr, SendConstNonNull, SendMutNonNull, SyncConstNonNull, SyncMutNonNull};
+use core::ptr::NonNull;
+
+/// Prevents implementations of the traits in this module outside of this crate.
+mod sealed {
+    /// Implemented by every wrapper type that implements `PtrWrapper`.
+    pub trait Sealed {}
+
+    /// Implemented by every type that implements `ToConstPtr`.
+    pub trait SealedConst {}
+}
+
+///
+/// Common interface of all wrapper types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+pub trait PtrWrapper: sealed::Sealed + Copy {
+    /// The type the wrapped pointer points to.
+    type Pointee: ?Sized;
+
+    /// The wrapped raw pointer type, `*mut Pointee` or `*const Pointee`,
+    /// or `NonNull<Pointee>` for the non-null wrappers.
+    type Raw: Copy;
+
+    /// The same kind of wrapper for a pointer to `Y`.
+    type Cast<Y>: PtrWrapper<Pointee = Y>;
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send or Sync.
+    ///
+    #[must_use]
+    fn inner(&self) -> Self::Raw;
+
+    ///
+    /// Returns inner `ptr` as a const raw pointer which is then no longer Send or Sync.
+    ///
+    #[must_use]
+    fn as_const_ptr(&self) -> *const Self::Pointee;
+
+    ///
+    /// Casts `ptr` to another data type while keeping the same wrapper kind.
+    ///
+    #[must_use]
+    fn cast<Y>(&self) -> Self::Cast<Y>;
+
+    ///
+    /// Returns true if `ptr` is null.
+    ///
+    #[must_use]
+    fn is_null(&self) -> bool {
+        self.as_const_ptr().is_null()
+    }
+}
+
+///
+/// Implemented by the wrappers that can be null, which are all of them except the non-null wrappers.
+///
+pub trait NullablePtr: PtrWrapper {
+    ///
+    /// Makes a null ptr of this wrapper kind.
+    ///
+    #[must_use]
+    fn null() -> Self
+    where
+        Self::Pointee: Sized;
+}
+
+///
+/// Implemented by the wrappers of mutable raw pointers,
+/// `SyncMutPtr`, `SendMutPtr`, `SyncMutNonNull` and `SendMutNonNull`.
+///
+pub trait MutPtr<T: ?Sized>: PtrWrapper<Pointee = T> {
+    ///
+    /// Returns inner `ptr` as a mutable raw pointer which is then no longer Send or Sync.
+    ///
+    #[must_use]
+    fn as_mut_ptr(&self) -> *mut T;
+}
+
+///
+/// Implemented by every wrapper that can be sent to other threads, which are all of them.
+///
+pub trait SendPtr<T: ?Sized>: PtrWrapper<Pointee = T> + Send {}
+
+///
+/// Implemented by the wrappers that can be shared between threads,
+/// `SyncMutPtr`, `SyncConstPtr`, `SyncMutNonNull` and `SyncConstNonNull`.
+///
+pub trait SyncPtr<T: ?Sized>: SendPtr<T> + Sync {}
+
+///
+/// Implemented by everything that can be turned into a `*const U`: raw pointers, `NonNull`
+/// and every wrapper that implements `PtrWrapper`. Used by `addr_eq` to accept any of them.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+pub trait ToConstPtr<U: ?Sized>: sealed::SealedConst {
+    ///
+    /// Returns `self` as a const raw pointer which is then no longer Send or Sync.
+    ///
+    #[must_use]
+    fn to_const_ptr(self) -> *const U;
+}
+
+impl<U: ?Sized> sealed::SealedConst for *const U {}
+impl<U: ?Sized> ToConstPtr<U> for *const U {
+    #[inline(always)]
+    fn to_const_ptr(self) -> *const U {
+        self
+    }
+}
+
+impl<U: ?Sized> sealed::SealedConst for *mut U {}
+impl<U: ?Sized> ToConstPtr<U> for *mut U {
+    #[inline(always)]
+    fn to_const_ptr(self) -> *const U {
+        self.cast_const()
+    }
+}
+
+impl<U: ?Sized> sealed::SealedConst for NonNull<U> {}
+impl<U: ?Sized> ToConstPtr<U> for NonNull<U> {
+    #[inline(always)]
+    fn to_const_ptr(self) -> *const U {
+        self.as_ptr().cast_const()
+    }
+}
+
+impl<P: PtrWrapper> sealed::SealedConst for P {}
+impl<P: PtrWrapper> ToConstPtr<P::Pointee> for P {
+    #[inline(always)]
+    fn to_const_ptr(self) -> *const P::Pointee {
+        self.as_const_ptr()
+    }
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> sealed::Sealed
+    for Ptr<T, M, S, D, Tag>
+{
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> PtrWrapper
+    for Ptr<T, M, S, D, Tag>
+{
+    type Pointee = T;
+    type Raw = M::Raw<T>;
+    type Cast<Y> = Ptr<Y, M, S, D, Tag>;
+
+    #[inline(always)]
+    fn inner(&self) -> M::Raw<T> {
+        M::raw(self.0)
+    }
+
+    #[inline(always)]
+    fn as_const_ptr(&self) -> *const T {
+        self.0
+    }
+
+    #[inline(always)]
+    fn cast<Y>(&self) -> Ptr<Y, M, S, D, Tag> {
+        Ptr::wrap(self.0.cast())
+    }
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> NullablePtr
+    for Ptr<T, M, S, D, Tag>
+{
+    #[inline(always)]
+    fn null() -> Self
+    where
+        T: Sized,
+    {
+        Self::wrap(core::ptr::null())
+    }
+}
+
+impl<T: ?Sized, S: ThreadSafety, D: DerefMode, Tag> MutPtr<T> for Ptr<T, Mut, S, D, Tag> {
+    #[inline(always)]
+    fn as_mut_ptr(&self) -> *mut T {
+        self.inner()
+    }
+}
+
+impl<T: ?Sized, M: Mutability, S: ThreadSafety, D: DerefMode, Tag> SendPtr<T>
+    for Ptr<T, M, S, D, Tag>
+{
+}
+
+impl<T: ?Sized, M: Mutability, D: DerefMode, Tag> SyncPtr<T> for Ptr<T, M, SendSync, D, Tag> {}
+
+/// Implements `PtrWrapper` and `SendPtr` for the non-null wrapper `$SelfType`.
+macro_rules! non_null_impl {
+    ($SelfType:ident) => {
+        impl<T: ?Sized> sealed::Sealed for $SelfType<T> {}
+
+        impl<T: ?Sized> PtrWrapper for $SelfType<T> {
+            type Pointee = T;
+            type Raw = NonNull<T>;
+            type Cast<Y> = $SelfType<Y>;
+
+            #[inline(always)]
+            fn inner(&self) -> NonNull<T> {
+                $SelfType::inner(self)
+            }
+
+            #[inline(always)]
+            fn as_const_ptr(&self) -> *const T {
+                $SelfType::inner(self).as_ptr()
+            }
+
+            #[inline(always)]
+            fn cast<Y>(&self) -> $SelfType<Y> {
+                $SelfType::cast(self)
+            }
+        }
+
+        impl<T: ?Sized> SendPtr<T> for $SelfType<T> {}
+    };
+}
+
+non_null_impl!(SyncMutNonNull);
+non_null_impl!(SyncConstNonNull);
+non_null_impl!(SendMutNonNull);
+non_null_impl!(SendConstNonNull);
+
+impl<T: ?Sized> MutPtr<T> for SyncMutNonNull<T> {
+    #[inline(always)]
+    fn as_mut_ptr(&self) -> *mut T {
+        self.as_ptr()
+    }
+}
+
+impl<T: ?Sized> MutPtr<T> for SendMutNonNull<T> {
+    #[inline(always)]
+    fn as_mut_ptr(&self) -> *mut T {
+        self.as_ptr()
+    }
+}
+
+impl<T: ?Sized> SyncPtr<T> for SyncMutNonNull<T> {}
+impl<T: ?Sized> SyncPtr<T> for SyncConstNonNull<T> {}
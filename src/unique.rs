@@ -0,0 +1,125 @@
+//! Move-only mutable pointer wrapper.
+//!
+//! All other wrappers are `Copy`, so a `SendMutPtr` that was sent to a worker can still be
+//! used by the sender. [`SendUniquePtr`] is neither `Clone` nor `Copy`, whoever holds it is
+//! the only one that can write through it:
+//!
+//! ```compile_fail
+//! use sync_ptr::SendUniquePtr;
+//!
+//! let ptr = SendUniquePtr::from_box(Box::new(1u32));
+//! let worker = std::thread::spawn(move || *ptr.get());
+//! let _ = ptr.get(); // `ptr` was moved to the worker.
+//! ```
+
+use crate::SendMutPtr;
+use alloc::boxed::Box;
+use core::fmt::{Formatter, Pointer};
+
+///
+/// Wrapped mutable raw pointer that is Send but not Sync and not `Copy`.
+///
+/// Because the wrapper can only be moved, it gives safe access to the pointee.
+/// `into_shared` gives up the uniqueness and returns a copyable `SendMutPtr`.
+///
+/// Like `SendMutPtr` the wrapper is invariant in `T`, otherwise `get_mut`
+/// could store a shorter-lived value into a pointee that outlives it:
+/// ```compile_fail
+/// # use sync_ptr::SendUniquePtr;
+/// fn shrink<'a>(p: SendUniquePtr<&'static str>) -> SendUniquePtr<&'a str> {
+///     p
+/// }
+/// ```
+///
+#[repr(transparent)]
+pub struct SendUniquePtr<T: ?Sized>(SendMutPtr<T>);
+
+impl<T: ?Sized> SendUniquePtr<T> {
+    ///
+    /// Makes `ptr` Send and unique.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads and writes for as long as the returned wrapper
+    /// or anything made from it with `get` or `get_mut` is used,
+    /// and nothing else may access the pointee during that time.
+    /// The `ptr` parameter must be able to handle being sent to other threads.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new(ptr: *mut T) -> Self {
+        Self(SendMutPtr::new(ptr))
+    }
+
+    ///
+    /// Returns a shared reference to the pointee.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        // SAFETY: `new` requires `ptr` to be valid and not accessed by anything else.
+        unsafe { &*self.0.inner() }
+    }
+
+    ///
+    /// Returns a mutable reference to the pointee.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn get_mut(&mut self) -> &mut T {
+        // SAFETY: `new` requires `ptr` to be valid and not accessed by anything else.
+        unsafe { &mut *self.0.inner() }
+    }
+
+    ///
+    /// Gives up the uniqueness and returns the pointer as a copyable `SendMutPtr`.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_shared(self) -> SendMutPtr<T> {
+        self.0
+    }
+
+    ///
+    /// Returns inner `ptr` which is then no longer Send.
+    ///
+    #[inline(always)]
+    #[must_use]
+    pub const fn inner(&self) -> *mut T {
+        self.0.inner()
+    }
+}
+
+impl<T: ?Sized + Send> SendUniquePtr<T> {
+    ///
+    /// Makes a unique Send pointer that owns the value of `val`.
+    ///
+    /// The value is leaked unless the pointer is turned back into a box
+    /// with `into_shared().into_box()`.
+    ///
+    #[inline(always)]
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn from_box(val: Box<T>) -> Self {
+        Self(SendMutPtr::from_box(val))
+    }
+}
+
+impl<T: ?Sized> Pointer for SendUniquePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&self.0, f)
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for SendUniquePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SendUniquePtr")
+            .field(&self.0.inner())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> From<SendUniquePtr<T>> for SendMutPtr<T> {
+    #[inline(always)]
+    fn from(val: SendUniquePtr<T>) -> Self {
+        val.into_shared()
+    }
+}
@@ -29,6 +29,7 @@ pub fn test_debug() {
 }
 
 #[test]
+#[allow(deprecated)] // Covers the conversions that were replaced by `into_*`.
 pub fn test_cmp() {
     unsafe {
         let mut data = vec![0; 4096];
@@ -76,6 +77,7 @@ pub fn test_cmp() {
 }
 
 #[test]
+#[allow(deprecated)] // Covers the conversions that were replaced by `into_*`.
 fn test() {
     unsafe {
         assert_eq!(size_of::<SyncConstPtr<c_void>>(), size_of::<*mut c_void>());
@@ -196,3 +198,1121 @@ fn test_mt() {
         assert_eq!(n.load(SeqCst), 456);
     }
 }
+
+trait Counter {
+    fn bump(&mut self) -> u32;
+}
+
+struct SimpleCounter(u32);
+
+impl Counter for SimpleCounter {
+    fn bump(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[test]
+fn test_unsized() {
+    unsafe {
+        let mut data = vec![1u32, 2, 3, 4];
+        let slice: *mut [u32] = data.as_mut_slice();
+        let sync_slice = slice.as_sync_mut();
+        assert_eq!(sync_slice.len(), 4);
+        assert!(!sync_slice.is_empty());
+        assert_eq!(sync_slice.into_const().into_send().len(), 4);
+        assert_eq!(sync_slice.cast::<u32>().inner(), data.as_mut_ptr());
+
+        let bytes = sync_slice.cast_slice::<u8>();
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(bytes.cast::<u8>().inner(), data.as_mut_ptr().cast::<u8>());
+
+        let rebuilt = SyncMutPtr::slice_from_raw_parts(sync_slice.cast::<u32>(), 2);
+        assert_eq!(rebuilt.len(), 2);
+        assert_ne!(rebuilt, sync_slice);
+        assert_eq!(
+            SyncMutPtr::slice_from_raw_parts(sync_slice.cast::<u32>(), 4),
+            sync_slice
+        );
+
+        std::thread::spawn(move || {
+            let slice = &mut *sync_slice.inner();
+            slice[3] = 40;
+            assert_eq!(slice.len(), 4);
+        })
+        .join()
+        .unwrap();
+        assert_eq!(data, vec![1, 2, 3, 40]);
+
+        let text: *const str = "hello";
+        let send_text = text.as_send_const();
+        let len = std::thread::spawn(move || (&*send_text.inner()).len())
+            .join()
+            .unwrap();
+        assert_eq!(len, 5);
+
+        let mut counter = SimpleCounter(0);
+        let dyn_ptr: *mut dyn Counter = &mut counter;
+        let send_dyn = dyn_ptr.as_send_mut();
+        let value = std::thread::spawn(move || (*send_dyn.inner()).bump())
+            .join()
+            .unwrap();
+        assert_eq!(value, 1);
+        assert_eq!(counter.0, 1);
+    }
+}
+
+#[test]
+fn test_non_null() {
+    use core::ptr::NonNull;
+
+    assert_eq!(
+        size_of::<Option<SyncMutNonNull<c_void>>>(),
+        size_of::<*mut c_void>()
+    );
+    assert_eq!(
+        size_of::<Option<SendConstNonNull<[u8]>>>(),
+        size_of::<*const [u8]>()
+    );
+
+    unsafe {
+        assert!(SyncMutNonNull::new_checked(null_mut::<c_void>()).is_none());
+        assert!(SyncMutNonNull::from_nullable(SyncMutPtr::<u64>::null()).is_none());
+
+        let mut value = 45u64;
+        let raw: *mut u64 = &mut value;
+        let nn = SyncMutNonNull::new_checked(raw).unwrap();
+        assert_eq!(nn.as_ptr(), raw);
+        assert_eq!(nn.inner(), NonNull::new(raw).unwrap());
+        assert_eq!(format!("{:?}", nn), format!("SyncMutNonNull({:?})", raw));
+
+        let nullable: SyncMutPtr<u64> = nn.into();
+        assert_eq!(nullable.inner(), raw);
+        assert_eq!(SyncMutNonNull::from_nullable(nullable), Some(nn));
+        assert_eq!(nn.as_nullable(), nullable);
+        let back: NonNull<u64> = nn.into();
+        assert_eq!(back.as_ptr(), raw);
+
+        let send_const = nn.into_const().into_send();
+        assert_eq!(send_const.as_ptr(), raw.cast_const());
+        assert_eq!(
+            send_const.into_sync().as_nullable().inner(),
+            raw.cast_const()
+        );
+        assert_eq!(send_const.into_mut().as_ptr(), raw);
+        assert_eq!(send_const.into_sync().into_mut(), nn);
+
+        let handle: Option<SendMutNonNull<u64>> = Some(nn.into_send());
+        std::thread::spawn(move || {
+            if let Some(handle) = handle {
+                handle.as_ptr().write(46);
+            }
+        })
+        .join()
+        .unwrap();
+        assert_eq!(value, 46);
+
+        let mut data = [1u8, 2, 3];
+        let slice = SyncConstNonNull::slice_from_raw_parts(
+            SyncConstNonNull::new_unchecked(data.as_mut_ptr().cast_const()),
+            3,
+        );
+        assert_eq!(slice.len(), 3);
+        assert!(!slice.is_empty());
+        assert_eq!(
+            SyncMutNonNull::<u32>::dangling().as_ptr(),
+            NonNull::dangling().as_ptr()
+        );
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+#[test]
+fn test_atomic() {
+    use core::sync::atomic::AtomicPtr;
+    use core::sync::atomic::Ordering::{Acquire, Relaxed, Release, SeqCst};
+
+    static PUBLISHED: SyncAtomicPtr<u64> = SyncAtomicPtr::null();
+
+    let mut data = vec![10u64, 20, 30];
+    let ptr = unsafe { data.as_mut_ptr().as_sync_mut() };
+    assert!(PUBLISHED.load(SeqCst).is_null());
+    assert_eq!(format!("{:?}", PUBLISHED), "SyncAtomicPtr(0x0)");
+
+    std::thread::spawn(move || PUBLISHED.store(ptr, Release))
+        .join()
+        .unwrap();
+    let loaded = PUBLISHED.load(Acquire);
+    assert_eq!(loaded, ptr);
+    assert_eq!(PUBLISHED.load_const(Acquire), ptr.into_const());
+    assert_eq!(unsafe { loaded.inner().read() }, 10);
+
+    assert_eq!(PUBLISHED.fetch_ptr_add(1, SeqCst), ptr);
+    assert_eq!(
+        PUBLISHED.fetch_byte_add(8, SeqCst).inner(),
+        data.as_mut_ptr().wrapping_add(1)
+    );
+    assert_eq!(
+        PUBLISHED.fetch_ptr_sub(2, SeqCst).inner(),
+        data.as_mut_ptr().wrapping_add(2)
+    );
+    assert_eq!(PUBLISHED.fetch_byte_sub(0, SeqCst), ptr);
+
+    assert_eq!(
+        PUBLISHED.compare_exchange(SyncMutPtr::null(), ptr, SeqCst, Relaxed),
+        Err(ptr)
+    );
+    assert_eq!(
+        PUBLISHED.compare_exchange(ptr, SyncMutPtr::null(), SeqCst, Relaxed),
+        Ok(ptr)
+    );
+    while PUBLISHED
+        .compare_exchange_weak(SyncMutPtr::null(), ptr, SeqCst, Relaxed)
+        .is_err()
+    {}
+    assert_eq!(PUBLISHED.swap(SyncMutPtr::null(), SeqCst), ptr);
+    assert_eq!(
+        PUBLISHED.fetch_update(SeqCst, SeqCst, |current| current.is_null().then_some(ptr)),
+        Ok(SyncMutPtr::null())
+    );
+    assert_eq!(
+        PUBLISHED.fetch_update(SeqCst, SeqCst, |current| current.is_null().then_some(ptr)),
+        Err(ptr)
+    );
+
+    let mut cell = SyncAtomicPtr::from(ptr);
+    *cell.get_mut() = SyncMutPtr::null();
+    assert!(cell.into_inner().is_null());
+
+    let atomic: AtomicPtr<u64> = SyncAtomicPtr::new(ptr).into();
+    let cell = unsafe { SyncAtomicPtr::from_atomic(atomic) };
+    assert_eq!(cell.load(SeqCst), ptr);
+    assert!(SyncAtomicPtr::<u64>::default().load(SeqCst).is_null());
+}
+
+#[test]
+fn test_slice_ptr() {
+    let mut data: alloc::vec::Vec<u32> = (0..10).collect();
+    let slice = unsafe { SendMutSlicePtr::from_raw_parts(data.as_mut_ptr(), data.len()) };
+    assert_eq!(slice.len(), 10);
+    assert_eq!(
+        slice.get(9).unwrap().inner(),
+        data.as_mut_ptr().wrapping_add(9)
+    );
+    assert!(slice.get(10).is_none());
+
+    let (left, right) = slice.split_at(4);
+    assert_eq!(left.len(), 4);
+    assert_eq!(right.len(), 6);
+    assert_eq!(right.as_ptr().inner(), data.as_mut_ptr().wrapping_add(4));
+
+    let handles: alloc::vec::Vec<_> = right
+        .chunks(4)
+        .chain(left.chunks(3))
+        .map(|mut chunk| {
+            std::thread::spawn(move || {
+                for value in unsafe { chunk.as_mut_slice() } {
+                    *value *= 10;
+                }
+            })
+        })
+        .collect();
+    assert_eq!(handles.len(), 4);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(data, vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+
+    let slice = unsafe { SendMutSlicePtr::from_raw_parts(data.as_mut_ptr(), data.len()) };
+    let mut exact = slice.chunks_exact(3);
+    assert_eq!(exact.len(), 3);
+    let lengths: alloc::vec::Vec<_> = exact.by_ref().map(|chunk| chunk.len()).collect();
+    assert_eq!(lengths, vec![3, 3, 3]);
+    let mut remainder = exact.into_remainder();
+    assert_eq!(unsafe { remainder.as_mut_slice() }, &[90]);
+
+    let shared = unsafe { SyncConstSlicePtr::from_raw_parts(data.as_ptr(), data.len()) };
+    let copy = shared;
+    assert_eq!(copy, shared);
+    assert_eq!(
+        shared
+            .chunks(4)
+            .map(|c| c.len())
+            .collect::<alloc::vec::Vec<_>>(),
+        vec![4, 4, 2]
+    );
+    let exact = shared.chunks_exact(4);
+    assert_eq!(exact.remainder().len(), 2);
+    let sums: alloc::vec::Vec<u32> = exact
+        .map(|chunk| std::thread::spawn(move || unsafe { chunk.as_slice() }.iter().sum()))
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(sums, vec![60, 220]);
+    assert_eq!(unsafe { shared.get(2).unwrap().inner().read() }, 20);
+    let (front, back) = shared.split_at(10);
+    assert_eq!(front, shared);
+    assert!(back.is_empty());
+}
+
+#[test]
+fn test_slice_chunks_without_element_bounds() {
+    struct Opaque(#[allow(dead_code)] u8);
+
+    let mut data = [Opaque(0), Opaque(1), Opaque(2)];
+    let shared = unsafe { SyncConstSlicePtr::from_raw_parts(data.as_ptr(), data.len()) };
+    let chunks = shared.chunks(2);
+    assert_eq!(chunks.clone().count(), 2);
+    assert!(format!("{:?}", chunks).starts_with("SyncConstChunks { rest: SyncConstSlicePtr {"));
+    let exact = shared.chunks_exact(2);
+    assert_eq!(exact.clone().count(), 1);
+    assert!(format!("{:?}", exact).contains("chunk_size: 2"));
+
+    let slice = unsafe { SendMutSlicePtr::from_raw_parts(data.as_mut_ptr(), data.len()) };
+    let (left, right) = slice.split_at(2);
+    assert!(format!("{:?}", left.chunks(1)).starts_with("SendMutChunks {"));
+    assert!(format!("{:?}", right.chunks_exact(1)).starts_with("SendMutChunksExact {"));
+}
+
+#[test]
+#[should_panic(expected = "mid > len")]
+fn test_slice_ptr_split_out_of_bounds() {
+    let mut data = [0u8; 4];
+    let slice = unsafe { SendMutSlicePtr::from_raw_parts(data.as_mut_ptr(), data.len()) };
+    let _ = slice.split_at(5);
+}
+
+#[test]
+fn test_tagged() {
+    let mut value = 7u64;
+    let ptr = unsafe { (&mut value as *mut u64).as_sync_mut() };
+    assert_eq!(SyncTaggedPtr::<u64, 3>::TAG_MASK, 0b111);
+
+    let tagged = SyncTaggedPtr::<u64, 3>::new(ptr, 5);
+    assert_eq!(tagged.tag(), 5);
+    assert_eq!(tagged.ptr(), ptr);
+    assert_eq!(tagged.into_raw().addr(), ptr.inner().addr() | 5);
+    assert_eq!(format!("{:p}", tagged), format!("{:p}", ptr));
+    assert_eq!(
+        format!("{:?}", tagged),
+        format!("SyncTaggedPtr {{ ptr: {:?}, tag: 5 }}", ptr.inner())
+    );
+
+    let retagged = tagged.with_tag(2);
+    assert_eq!(retagged.tag(), 2);
+    assert_ne!(retagged, tagged);
+    assert!(retagged.ptr_eq(&tagged));
+    assert_eq!(tagged.map_tag(|tag| tag + 1).tag(), 6);
+    assert_eq!(tagged.map_ptr(|_| SyncMutPtr::null()).tag(), 5);
+    assert!(tagged.map_ptr(|_| SyncMutPtr::null()).is_null());
+    assert!(!tagged.is_null());
+    assert!(SyncTaggedPtr::<u64, 3>::null().is_null());
+    assert_eq!(
+        unsafe { SyncTaggedPtr::<u64, 3>::from_raw(tagged.into_raw()) },
+        tagged
+    );
+
+    let send = tagged.into_send_tagged();
+    let (tag, read) =
+        std::thread::spawn(move || (send.tag(), unsafe { send.ptr().inner().read() }))
+            .join()
+            .unwrap();
+    assert_eq!((tag, read), (5, 7));
+    assert_eq!(unsafe { send.into_sync_tagged() }, tagged);
+    assert_eq!(SendTaggedPtr::<u64, 2>::new(ptr.into_send(), 3).tag(), 3);
+}
+
+#[test]
+#[should_panic(expected = "tag does not fit into BITS bits")]
+fn test_tagged_overflow() {
+    let _ = SyncTaggedPtr::<u64, 2>::null().with_tag(4);
+}
+
+#[test]
+fn test_provenance() {
+    const SENTINEL: SyncConstPtr<u32> = SyncConstPtr::without_provenance(0x10);
+    const DANGLING: SendMutPtr<u64> = SendMutPtr::dangling();
+    assert_eq!(SENTINEL.addr(), 0x10);
+    assert_eq!(DANGLING.addr(), align_of::<u64>());
+
+    let mut data = [1u32, 2, 3, 4];
+    let ptr = unsafe { data.as_mut_ptr().as_sync_mut() };
+    let third = ptr.with_addr(ptr.addr() + 2 * size_of::<u32>());
+    assert_eq!(unsafe { third.inner().read() }, 3);
+    let second = third.map_addr(|addr| addr - size_of::<u32>());
+    assert_eq!(unsafe { second.inner().read() }, 2);
+
+    let send = ptr.into_const().into_send();
+    let addr = send.expose_provenance();
+    let handle = std::thread::spawn(move || {
+        let restored = unsafe { SendConstPtr::<u32>::with_exposed_provenance(addr) };
+        unsafe { restored.inner().add(3).read() }
+    });
+    assert_eq!(handle.join().unwrap(), 4);
+    let restored = unsafe { SyncMutPtr::<u32>::with_exposed_provenance(addr) };
+    assert_eq!(restored, ptr);
+
+    let slice = unsafe { (&raw mut data as *mut [u32]).as_send_mut() };
+    let shifted = slice.map_addr(|addr| addr + size_of::<u32>());
+    assert_eq!(shifted.len(), 4);
+    assert_eq!(shifted.addr(), ptr.addr() + size_of::<u32>());
+}
+
+#[test]
+fn test_arithmetic() {
+    let mut data: alloc::vec::Vec<u64> = (0..8).collect();
+    let start = unsafe { data.as_mut_ptr().as_send_mut() };
+    let sum = std::thread::spawn(move || {
+        let mut sum = 0;
+        let mut cursor = start;
+        let end = unsafe { start.add(8) };
+        while cursor != end {
+            unsafe {
+                sum += cursor.read();
+                cursor = cursor.add(1);
+            }
+        }
+        assert_eq!(unsafe { end.offset_from(start) }, 8);
+        sum
+    })
+    .join()
+    .unwrap();
+    assert_eq!(sum, 28);
+
+    unsafe {
+        let base = data.as_ptr().as_sync_const();
+        let fourth: SyncConstPtr<u64> = base.add(4);
+        assert_eq!(fourth.read(), 4);
+        assert_eq!(fourth.sub(1).read(), 3);
+        assert_eq!(fourth.offset(-2).read(), 2);
+        assert_eq!(fourth.byte_add(8).read(), 5);
+        assert_eq!(fourth.byte_sub(8).read(), 3);
+        assert_eq!(fourth.byte_offset(-16).read(), 2);
+        assert_eq!(fourth.byte_offset_from(base), 32);
+        assert_eq!(base.wrapping_add(3).read(), 3);
+        assert_eq!(fourth.wrapping_sub(3).read(), 1);
+        assert_eq!(fourth.wrapping_offset(-4), base);
+        assert_eq!(base.wrapping_byte_add(8).read(), 1);
+        assert_eq!(fourth.wrapping_byte_sub(8).read(), 3);
+        assert_eq!(fourth.wrapping_byte_offset(8).read(), 5);
+        assert_eq!((base + 6).read(), 6);
+        assert_eq!((fourth - 4), base);
+
+        let send_mut: SendMutPtr<u64> = start + 7;
+        send_mut.write(70);
+        let sync_mut: SyncMutPtr<u64> = send_mut.into_sync() - 7;
+        assert_eq!(sync_mut.add(7).read(), 70);
+
+        let slice = (&raw mut data[..4]).as_sync_mut();
+        let shifted = slice.byte_add(8);
+        assert_eq!(shifted.len(), 4);
+        assert_eq!(shifted.byte_offset_from(slice), 8);
+    }
+}
+
+#[test]
+fn test_memory_ops() {
+    let mut src_data = [1u32, 2, 3, 4];
+    let mut dst_data = [0u32; 4];
+    unsafe {
+        let src = src_data.as_mut_ptr().as_sync_mut();
+        let dst = dst_data.as_mut_ptr().as_send_mut();
+
+        src.copy_to_nonoverlapping(dst, 2);
+        assert_eq!(dst_data, [1, 2, 0, 0]);
+        dst.add(2)
+            .copy_from_nonoverlapping(src.into_const().add(2), 2);
+        assert_eq!(dst_data, [1, 2, 3, 4]);
+        src.add(1).copy_to(src, 3);
+        assert_eq!(src_data, [2, 3, 4, 4]);
+        src.add(1).copy_from(src, 3);
+        assert_eq!(src_data, [2, 2, 3, 4]);
+        src.into_const().into_send().copy_to(dst, 1);
+        assert_eq!(dst_data[0], 2);
+
+        let worker = std::thread::spawn(move || {
+            assert_eq!(dst.read(), 2);
+            dst.write(10);
+            dst.add(1).write_volatile(20);
+            assert_eq!(dst.add(1).read_volatile(), 20);
+            assert_eq!(dst.add(2).replace(30), 3);
+            dst.add(3).swap(dst);
+        });
+        worker.join().unwrap();
+        assert_eq!(dst_data, [4, 20, 30, 10]);
+
+        dst.write_bytes(0, 2);
+        assert_eq!(dst_data, [0, 0, 30, 10]);
+
+        let bytes = [0u8, 1, 0, 0, 0];
+        let unaligned = bytes.as_ptr().add(1).cast::<u32>().as_sync_const();
+        assert_eq!(unaligned.read_unaligned(), 1);
+        let mut out = [0u8; 5];
+        let unaligned_out = out.as_mut_ptr().add(1).cast::<u32>().as_sync_mut();
+        unaligned_out.write_unaligned(1);
+        assert_eq!(out, bytes);
+    }
+}
+
+#[test]
+fn test_alignment() {
+    #[repr(C, align(64))]
+    struct Page([u8; 128]);
+
+    let mut page = Page([0; 128]);
+    let base = unsafe { page.0.as_mut_ptr().as_sync_mut() };
+    assert!(base.is_aligned());
+    assert!(base.is_aligned_to(64));
+    assert!(base.cast::<u64>().is_aligned());
+
+    let odd = base.wrapping_add(3);
+    assert!(!odd.is_aligned_to(2));
+    assert!(!odd.cast::<u32>().is_aligned());
+    assert_eq!(odd.align_offset(4), 1);
+    assert_eq!(odd.align_up(16), base.wrapping_add(16));
+    assert_eq!(odd.align_down(16), base);
+    assert_eq!(base.align_up(16), base);
+    assert_eq!(
+        odd.into_const().into_send().align_down(2).addr(),
+        base.addr() + 2
+    );
+
+    assert!(AlignedSyncPtr::<u32, 16>::new(odd.cast()).is_none());
+    let aligned = AlignedSyncPtr::<u32, 16>::new(base.wrapping_add(32).cast()).unwrap();
+    assert_eq!(aligned.ptr(), base.wrapping_add(32).cast());
+    std::thread::spawn(move || unsafe { aligned.write(0x0102_0304) })
+        .join()
+        .unwrap();
+    assert_eq!(unsafe { aligned.read() }, 0x0102_0304);
+    assert_eq!(unsafe { aligned.into_sync_const().read() }, 0x0102_0304);
+    assert_eq!(aligned.cast::<u8>().inner(), page.0[32..].as_mut_ptr());
+    assert_eq!(AlignedSyncPtr::<u64, 8>::dangling().ptr().addr(), 8);
+}
+
+#[test]
+#[should_panic(expected = "align is not a power of two")]
+fn test_alignment_not_power_of_two() {
+    let _ = SyncConstPtr::<u8>::dangling().is_aligned_to(3);
+}
+
+#[test]
+fn test_validated_constructors() {
+    use core::error::Error;
+
+    let mut data = [0u64; 4];
+    let base = data.as_mut_ptr();
+    let start = base.addr();
+    let end = start + size_of_val(&data);
+
+    unsafe {
+        assert_eq!(SyncMutPtr::try_new(base).unwrap().inner(), base);
+        assert_eq!(SendMutPtr::<u64>::try_new(null_mut()), Err(PtrError::Null));
+        assert_eq!(
+            SyncConstPtr::try_new(base.cast::<u8>().add(1).cast::<u64>().cast_const()),
+            Err(PtrError::Misaligned {
+                addr: start + 1,
+                align: align_of::<u64>()
+            })
+        );
+        assert!(SendConstPtr::try_new_in(base.add(3).cast_const(), start..end).is_ok());
+        assert_eq!(
+            SyncMutPtr::try_new_in(base.add(4), start..end),
+            Err(PtrError::OutOfRange {
+                addr: end,
+                size: 8,
+                range: start..end
+            })
+        );
+        let exposed = base.expose_provenance();
+        assert_eq!(
+            SyncMutPtr::<u64>::try_from_raw(exposed).unwrap().inner(),
+            base
+        );
+        assert_eq!(SyncMutPtr::<u64>::try_from_raw(0), Err(PtrError::Null));
+    }
+
+    let ptr: SyncMutPtr<u64> = base.try_into().unwrap();
+    assert_eq!(ptr.inner(), base);
+    let ptr = SendConstPtr::<u64>::try_from(base.cast_const()).unwrap();
+    assert_eq!(ptr.inner(), base.cast_const());
+    assert_eq!(
+        SendMutPtr::<u64>::try_from(6usize),
+        Err(PtrError::Misaligned { addr: 6, align: 8 })
+    );
+    assert_eq!(SyncConstPtr::<u64>::try_from(0usize), Err(PtrError::Null));
+
+    assert_eq!(PtrError::Null.to_string(), "pointer is null");
+    assert_eq!(
+        PtrError::Misaligned { addr: 6, align: 8 }.to_string(),
+        "pointer 0x6 is not aligned to 8 bytes"
+    );
+    assert_eq!(
+        PtrError::OutOfRange {
+            addr: 0x20,
+            size: 8,
+            range: 0x10..0x20
+        }
+        .to_string(),
+        "pointer 0x20 to 8 bytes is outside of the range 0x10..0x20"
+    );
+    let boxed: alloc::boxed::Box<dyn Error> = alloc::boxed::Box::new(PtrError::Null);
+    assert!(boxed.source().is_none());
+
+    let aligned = AlignedSyncPtr::<u64, 16>::try_new(SyncMutPtr::without_provenance(8));
+    assert_eq!(aligned, Err(PtrError::Misaligned { addr: 8, align: 16 }));
+}
+
+fn sum_in_thread<P: SendPtr<[u32]> + 'static>(ptr: P) -> u32 {
+    std::thread::spawn(move || unsafe { (*ptr.as_const_ptr()).iter().sum() })
+        .join()
+        .unwrap()
+}
+
+fn fill<P: MutPtr<u32> + SyncPtr<u32>>(ptr: P, value: u32) {
+    unsafe { ptr.as_mut_ptr().write(value) }
+}
+
+#[test]
+fn test_traits() {
+    let mut data = vec![1u32, 2, 3];
+    let raw: *mut [u32] = data.as_mut_slice();
+    assert_eq!(sum_in_thread(unsafe { raw.as_sync_mut() }), 6);
+    assert_eq!(sum_in_thread(unsafe { raw.as_send_const() }), 6);
+
+    let mut value = 0u32;
+    let ptr = unsafe { core::ptr::from_mut(&mut value).as_sync_mut() };
+    fill(ptr, 7);
+    assert_eq!(value, 7);
+
+    let bytes = PtrWrapper::cast::<u8>(&ptr);
+    assert_eq!(PtrWrapper::inner(&bytes), ptr.inner().cast::<u8>());
+    assert!(!PtrWrapper::is_null(&bytes));
+    assert!(<SendConstPtr<u32> as NullablePtr>::null().is_null());
+}
+
+#[test]
+fn test_traits_non_null() {
+    use core::ptr::NonNull;
+
+    let mut data = vec![1u32, 2, 3];
+    let raw = NonNull::from(data.as_mut_slice());
+    assert_eq!(sum_in_thread(unsafe { SyncMutNonNull::new(raw) }), 6);
+    assert_eq!(sum_in_thread(unsafe { SendConstNonNull::new(raw) }), 6);
+
+    let mut value = 0u32;
+    let ptr = unsafe { SyncMutNonNull::new(NonNull::from(&mut value)) };
+    fill(ptr, 7);
+    assert_eq!(value, 7);
+
+    let bytes = PtrWrapper::cast::<u8>(&ptr);
+    assert_eq!(PtrWrapper::inner(&bytes), ptr.inner().cast::<u8>());
+    assert!(!PtrWrapper::is_null(&bytes));
+}
+
+fn offset_in_bytes<M: marker::Mutability, S: marker::ThreadSafety>(
+    ptr: Ptr<u32, M, S>,
+    base: Ptr<u32, M, S>,
+) -> usize {
+    ptr.addr() - base.addr()
+}
+
+#[test]
+fn test_typestate() {
+    let mut data = [1u32, 2, 3];
+    let raw = data.as_mut_ptr();
+
+    let ptr: Ptr<u32, marker::Mut, marker::SendSync> = unsafe { raw.as_sync_mut() };
+    let alias: SyncMutPtr<u32> = ptr;
+    assert_eq!(offset_in_bytes(alias.wrapping_add(2), alias), 8);
+    assert_eq!(
+        offset_in_bytes(
+            alias.into_const().into_send().wrapping_add(1),
+            alias.into_const().into_send()
+        ),
+        4
+    );
+
+    unsafe { ptr.wrapping_add(1).write(5) };
+    assert_eq!(data, [1, 5, 3]);
+
+    let null = SendConstPtr::<u32>::null();
+    assert_eq!(format!("{null:?}"), "SendConstPtr(0x0)");
+    assert_eq!(
+        format!("{:?}", SendMutPtr::<u32>::null()),
+        "SendMutPtr(0x0)"
+    );
+    assert_eq!(
+        format!("{:?}", SyncConstPtr::<u32>::null()),
+        "SyncConstPtr(0x0)"
+    );
+}
+
+#[test]
+fn test_conversions() {
+    let mut value = 3u32;
+    let sync_mut = unsafe { core::ptr::from_mut(&mut value).as_sync_mut() };
+
+    let sync_const: SyncConstPtr<u32> = sync_mut.into_const();
+    let send_mut: SendMutPtr<u32> = sync_mut.into_send();
+    let send_const: SendConstPtr<u32> = send_mut.into_const();
+    assert_eq!(sync_const.into_send(), send_const);
+
+    let upgraded: SyncMutPtr<u32> = unsafe { send_const.into_mut().into_sync() };
+    assert_eq!(upgraded, sync_mut);
+    unsafe { upgraded.write(4) };
+    assert_eq!(value, 4);
+
+    // Deref gives up Send and Sync, so it is available on every wrapper.
+    let raw: *const u32 = *send_const;
+    assert_eq!(raw, sync_mut.inner().cast_const());
+}
+
+#[test]
+fn test_explicit() {
+    let mut value = 5u64;
+    let auto = unsafe { core::ptr::from_mut(&mut value).as_sync_mut() };
+    let ptr: explicit::SyncMutPtr<u64> = auto.into_explicit();
+
+    assert!(!ptr.is_null());
+    assert!(explicit::SendConstPtr::<u64>::null().is_null());
+    assert_eq!(ptr.inner(), auto.inner());
+    assert_eq!(ptr.into_auto_deref(), auto);
+    assert_eq!(format!("{ptr:?}"), format!("{auto:?}"));
+
+    unsafe {
+        assert_eq!(ptr.as_ref(), Some(&5));
+        *ptr.as_mut().unwrap() = 6;
+        assert_eq!(ptr.into_const().into_send().read(), 6);
+        assert_eq!(explicit::SyncConstPtr::<u64>::null().as_ref(), None);
+    }
+
+    let boxed = alloc::boxed::Box::into_raw(alloc::boxed::Box::new(alloc::rc::Rc::new(1)));
+    let rc = unsafe { (*boxed).clone() };
+    let owner = unsafe { explicit::SendMutPtr::new(boxed) };
+    assert_eq!(alloc::rc::Rc::strong_count(&rc), 2);
+    unsafe {
+        owner.drop_in_place();
+        alloc::alloc::dealloc(
+            boxed.cast(),
+            core::alloc::Layout::new::<alloc::rc::Rc<i32>>(),
+        );
+    }
+    assert_eq!(alloc::rc::Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_safe_constructors() {
+    let value = 7u32;
+    let shared = SyncConstPtr::from_ref(&value);
+    let send_shared = SendConstPtr::from_ref(&value);
+    assert_eq!(shared.inner(), core::ptr::from_ref(&value));
+    assert_eq!(shared.into_send(), send_shared);
+
+    let text: &str = "text";
+    assert_eq!(
+        SyncConstPtr::from_ref(text).inner(),
+        core::ptr::from_ref(text)
+    );
+
+    let mut counter = 0u32;
+    let ptr = SyncMutPtr::from_mut(&mut counter);
+    std::thread::scope(|s| {
+        s.spawn(move || unsafe { ptr.write(1) });
+    });
+    assert_eq!(counter, 1);
+    assert_eq!(
+        SendMutPtr::from_mut(&mut counter).inner(),
+        core::ptr::from_mut(&mut counter)
+    );
+
+    let owned = SendMutPtr::from_box(alloc::boxed::Box::new(alloc::string::String::from("owned")));
+    let len = std::thread::spawn(move || {
+        let owned = unsafe { alloc::boxed::Box::from_raw(owned.inner()) };
+        owned.len()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(len, 5);
+
+    let shared_box = SyncMutPtr::from_box(alloc::boxed::Box::new(3u64));
+    drop(unsafe { alloc::boxed::Box::from_raw(shared_box.inner()) });
+}
+
+#[repr(C)]
+struct ExternContext {
+    _private: [u8; 0],
+}
+
+unsafe impl ExternSync for ExternContext {}
+
+#[repr(C)]
+struct ExternStream {
+    _private: [u8; 0],
+}
+
+unsafe impl ExternSend for ExternStream {}
+
+#[test]
+fn test_extern_traits() {
+    let context = core::ptr::without_provenance_mut::<ExternContext>(0x1000);
+    let sync_mut = context.into_sync_mut();
+    assert_eq!(sync_mut, SyncMutPtr::from_extern(context));
+    assert_eq!(sync_mut.into_const(), context.into_sync_const());
+    assert_eq!(
+        context.cast_const().into_send_const(),
+        SendConstPtr::from_extern(context.cast_const())
+    );
+    assert_eq!(
+        explicit::SyncConstPtr::from_extern(context.cast_const()).addr(),
+        0x1000
+    );
+    std::thread::spawn(move || assert_eq!(sync_mut.addr(), 0x1000))
+        .join()
+        .unwrap();
+
+    let stream = core::ptr::without_provenance_mut::<ExternStream>(0x2000);
+    let send_mut: SendMutPtr<ExternStream> = stream.into_send_mut();
+    assert_eq!(send_mut, SendMutPtr::from_extern(stream));
+    assert_eq!(stream.into_send_const(), send_mut.into_const());
+}
+
+static RELEASED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+unsafe extern "C" fn release_buffer(ptr: *mut u64) {
+    RELEASED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    drop(alloc::boxed::Box::from_raw(ptr));
+}
+
+sync_handle! {
+    /// Shared test handle.
+    struct SharedHandle: Sync;
+
+    struct BufferHandle: Send (u64), drop = release_buffer;
+}
+
+fn assert_send_sync<T: Send + Sync>() {}
+fn assert_send<T: Send>() {}
+
+#[test]
+fn test_sync_handle() {
+    assert_send_sync::<SharedHandle>();
+    assert_send::<BufferHandle>();
+    assert_eq!(size_of::<SharedHandle>(), size_of::<*mut c_void>());
+
+    let shared = unsafe { SharedHandle::from_raw(core::ptr::without_provenance_mut(0x10)) };
+    let copy = shared;
+    assert_eq!(copy, shared);
+    assert_ne!(shared, SharedHandle::null());
+    assert!(!shared.is_null());
+    assert_eq!(shared.as_raw().addr(), 0x10);
+    assert_eq!(shared.as_ptr().addr(), 0x10);
+    assert_eq!(shared.into_raw().addr(), 0x10);
+    assert_eq!(format!("{:?}", SharedHandle::null()), "SharedHandle(0x0)");
+
+    let released = RELEASED.load(std::sync::atomic::Ordering::SeqCst);
+    let buffer =
+        unsafe { BufferHandle::from_raw(alloc::boxed::Box::into_raw(alloc::boxed::Box::new(5))) };
+    let buffer = std::thread::spawn(move || {
+        assert_eq!(unsafe { buffer.as_ptr().read() }, 5);
+        buffer
+    })
+    .join()
+    .unwrap();
+    drop(buffer);
+    drop(BufferHandle::null());
+    assert_eq!(
+        RELEASED.load(std::sync::atomic::Ordering::SeqCst),
+        released + 1
+    );
+
+    let raw =
+        unsafe { BufferHandle::from_raw(alloc::boxed::Box::into_raw(alloc::boxed::Box::new(6))) }
+            .into_raw();
+    assert_eq!(
+        RELEASED.load(std::sync::atomic::Ordering::SeqCst),
+        released + 1
+    );
+    drop(unsafe { alloc::boxed::Box::from_raw(raw) });
+}
+
+enum WindowTag {}
+enum TextureTag {}
+
+#[test]
+fn test_tagged_handles() {
+    let window: SyncMutPtr<c_void, WindowTag> =
+        unsafe { SyncMutPtr::new(core::ptr::without_provenance_mut::<c_void>(0x40)) }.retag();
+    assert_send_sync::<SyncConstPtr<c_void, TextureTag>>();
+    assert_eq!(size_of_val(&window), size_of::<*mut c_void>());
+    assert_eq!(format!("{:?}", window), "SyncMutPtr(0x40)");
+
+    let bytes: SyncMutPtr<u8, WindowTag> = window.cast();
+    assert_eq!(bytes.addr(), 0x40);
+    let window_const: SendConstPtr<c_void, WindowTag> = window.into_const().into_send();
+    assert_eq!(window_const.inner(), window.inner().cast_const());
+
+    let texture: SyncMutPtr<c_void, TextureTag> = window.retag();
+    assert_eq!(texture.inner(), window.inner());
+    assert_eq!(texture.retag::<()>(), window.retag::<()>());
+}
+
+#[test]
+fn test_borrowed() {
+    assert_send_sync::<SyncRef<'static, u32>>();
+    assert_send::<SendMut<'static, u32>>();
+    assert_eq!(size_of::<SyncRef<'static, [u8]>>(), size_of::<*const [u8]>());
+
+    let values = [1u32, 2, 3];
+    let shared = SyncRef::new(&values[..]);
+    let copy = shared;
+    let sum = std::thread::scope(|s| s.spawn(move || copy.get().iter().sum::<u32>()).join());
+    assert_eq!(sum.unwrap(), 6);
+    assert_eq!(shared.inner(), core::ptr::from_ref(&values[..]));
+    assert_eq!(shared.ptr(), SyncConstPtr::from_ref(&values[..]));
+
+    let mut counter = 1u32;
+    let mut exclusive = SendMut::from(&mut counter);
+    *exclusive.get_mut() += 1;
+    assert_eq!(*exclusive.get(), 2);
+    std::thread::scope(|s| {
+        s.spawn(move || *exclusive.into_mut() *= 10);
+    });
+    assert_eq!(counter, 20);
+    assert_eq!(
+        format!("{:?}", SendMut::new(&mut counter)),
+        format!("SendMut({:p})", &raw const counter)
+    );
+}
+
+#[test]
+fn test_unique() {
+    assert_send::<SendUniquePtr<c_void>>();
+
+    let mut unique = SendUniquePtr::from_box(alloc::boxed::Box::new(alloc::vec![1u32]));
+    unique.get_mut().push(2);
+    let unique = std::thread::spawn(move || {
+        let mut unique = unique;
+        unique.get_mut().push(3);
+        unique
+    })
+    .join()
+    .unwrap();
+    assert_eq!(unique.get(), &[1, 2, 3]);
+
+    let raw = unique.inner();
+    let shared: SendMutPtr<_> = unique.into_shared();
+    assert_eq!(shared.inner(), raw);
+    let copy = shared;
+    assert_eq!(copy, shared);
+    drop(unsafe { alloc::boxed::Box::from_raw(raw) });
+
+    let mut value = 5u8;
+    let unique = unsafe { SendUniquePtr::new(&raw mut value) };
+    assert_eq!(format!("{:?}", unique), format!("SendUniquePtr({:p})", &raw const value));
+}
+
+#[test]
+fn test_from_other_pointers() {
+    let mut value = 3u32;
+    let expected = core::ptr::from_ref(&value);
+    unsafe {
+        assert_eq!((&value).as_sync_const().inner(), expected);
+        let exclusive = &mut value;
+        assert_eq!(exclusive.as_send_const().inner(), expected);
+
+        let non_null = core::ptr::NonNull::from(&mut value);
+        assert_eq!(non_null.as_sync_mut().inner(), non_null.as_ptr());
+        assert_eq!(non_null.as_send_const().inner(), expected);
+    }
+
+    let cell = core::cell::UnsafeCell::new(1u32);
+    let ptr = unsafe { (&cell).as_sync_mut() };
+    std::thread::scope(|s| {
+        s.spawn(move || unsafe { ptr.write(2) });
+    });
+    assert_eq!(cell.into_inner(), 2);
+
+    let mut counter = 0u32;
+    let ptr = unsafe { (&mut counter).into_sync_mut() };
+    std::thread::scope(|s| {
+        s.spawn(move || unsafe { ptr.write(4) });
+    });
+    let send = unsafe { (&mut counter).into_send_mut() };
+    unsafe { *send.inner() += 1 };
+    assert_eq!(counter, 5);
+}
+
+#[test]
+fn test_owning_round_trips() {
+    let boxed = SyncMutPtr::from_box(alloc::boxed::Box::new(alloc::vec![1u8, 2]));
+    let len = std::thread::spawn(move || unsafe { (*boxed.inner()).len() })
+        .join()
+        .unwrap();
+    assert_eq!(len, 2);
+    drop(unsafe { boxed.into_box() });
+
+    let arc = std::sync::Arc::new(alloc::string::String::from("shared"));
+    let ptr = SyncConstPtr::from_arc(std::sync::Arc::clone(&arc));
+    assert_eq!(std::sync::Arc::strong_count(&arc), 2);
+    let workers: alloc::vec::Vec<_> = (0..3)
+        .map(|_| std::thread::spawn(move || unsafe { ptr.clone_arc() }.len()))
+        .collect();
+    for worker in workers {
+        assert_eq!(worker.join().unwrap(), 6);
+    }
+    assert_eq!(std::sync::Arc::strong_count(&arc), 2);
+    drop(unsafe { ptr.into_arc() });
+    assert_eq!(std::sync::Arc::strong_count(&arc), 1);
+}
+
+#[test]
+fn test_cross_type_comparison() {
+    let mut values = [1u32, 2];
+    let first = SyncMutPtr::from_mut(&mut values[0]);
+    let second = unsafe { first.add(1) };
+    let raw = first.inner();
+
+    assert_eq!(first, first.into_const().into_send());
+    assert_eq!(first.into_send(), first.into_explicit());
+    assert_eq!(first, raw);
+    assert_eq!(raw.cast_const(), first.into_send());
+    assert_eq!(core::ptr::NonNull::new(raw).unwrap(), first);
+    assert_ne!(second, raw);
+    assert!(first < second.into_const());
+    assert!(raw < second);
+    assert!(second > raw.cast_const());
+    assert!(first < core::ptr::NonNull::new(second.inner()).unwrap());
+
+    let whole = SyncConstPtr::from_ref(&values[..]);
+    assert!(whole.addr_eq(first));
+    assert!(whole.addr_eq(raw.cast_const()));
+    assert!(whole.addr_eq(raw));
+    assert!(whole.addr_eq(core::ptr::NonNull::new(raw).unwrap()));
+    assert!(whole.addr_eq(unsafe { SendConstNonNull::new_unchecked(raw) }));
+    assert!(whole.addr_eq(first.into_explicit()));
+    assert!(!whole.addr_eq(second));
+    assert!(!whole.addr_eq(second.inner()));
+
+    let shared: SyncConstPtr<u32> = first.into();
+    let sent: SendMutPtr<u32> = first.into();
+    let sent_shared: SendConstPtr<u32> = first.into();
+    assert_eq!(SendConstPtr::from(shared), sent_shared);
+    assert_eq!(SendConstPtr::from(sent), sent_shared);
+}
+
+#[test]
+fn test_bulk_conversions() {
+    let mut values = [10u32, 20, 30];
+    let base = values.as_mut_ptr();
+    let mut raw = [base, unsafe { base.add(1) }, unsafe { base.add(2) }];
+
+    let wrapped = unsafe { SyncMutPtr::from_raw_slice(&raw) };
+    assert_eq!(wrapped.len(), 3);
+    assert_eq!(wrapped[1], raw[1]);
+    assert_eq!(SyncMutPtr::as_raw_slice(wrapped), &raw);
+
+    let wrapped = unsafe { SendMutPtr::from_raw_slice_mut(&mut raw) };
+    wrapped.swap(0, 2);
+    assert_eq!(raw[0], unsafe { base.add(2) });
+
+    let array = unsafe { SyncConstPtr::from_raw_array(raw.map(<*mut u32>::cast_const)) };
+    let sum = std::thread::spawn(move || array.iter().map(|p| unsafe { p.read() }).sum::<u32>())
+        .join()
+        .unwrap();
+    assert_eq!(sum, 60);
+
+    let vec = alloc::vec::Vec::from(raw);
+    let (ptr, capacity) = (vec.as_ptr(), vec.capacity());
+    let wrapped = unsafe { SyncMutPtr::from_raw_vec(vec) };
+    assert_eq!(wrapped.as_ptr().cast::<*mut u32>(), ptr);
+    let vec = SyncMutPtr::into_raw_vec(wrapped);
+    assert_eq!((vec.as_ptr(), vec.capacity()), (ptr, capacity));
+    assert_eq!(vec, raw);
+}
+
+extern "C" fn add_one(value: u32) -> u32 {
+    value + 1
+}
+
+extern "C-unwind" fn add_one_unwind(value: u32) -> u32 {
+    value + 1
+}
+
+#[test]
+fn test_fn_ptr() {
+    type Callback = extern "C" fn(u32) -> u32;
+    assert_send_sync::<SyncFnPtr<Callback>>();
+    assert_eq!(size_of::<SyncFnPtr<Callback>>(), size_of::<Callback>());
+
+    let callback = unsafe { SyncFnPtr::new(add_one as Callback) };
+    let result = std::thread::spawn(move || callback.get()(1)).join().unwrap();
+    assert_eq!(result, 2);
+
+    let symbol = add_one as *const c_void;
+    let loaded = unsafe { SyncFnPtr::<Callback>::from_symbol(symbol) }.unwrap();
+    assert_eq!(loaded, callback);
+    assert_eq!(loaded.addr(), symbol);
+    assert!(unsafe { SyncFnPtr::<Callback>::from_symbol(core::ptr::null()) }.is_none());
+
+    let as_unsafe: SyncFnPtr<unsafe extern "C" fn(u32) -> u32> = unsafe { callback.cast() };
+    assert_eq!(unsafe { as_unsafe.get()(41) }, 42);
+    assert_eq!(as_unsafe.addr(), callback.addr());
+    assert_eq!(format!("{:?}", callback), format!("SyncFnPtr({:p})", symbol));
+    assert_eq!(format!("{:p}", callback), format!("{:p}", symbol));
+
+    type UnwindCallback = extern "C-unwind" fn(u32) -> u32;
+    let unwinding = unsafe { SyncFnPtr::new(add_one_unwind as UnwindCallback) };
+    assert_eq!(unwinding.get()(2), 3);
+}
+
+static DELETED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+unsafe extern "C" fn delete_boxed(ptr: *mut u32) {
+    drop(alloc::boxed::Box::from_raw(ptr));
+    DELETED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+}
+
+struct CountingDeleter<'a>(&'a core::cell::Cell<usize>);
+
+impl Deleter<[u8]> for CountingDeleter<'_> {
+    unsafe fn delete(&mut self, ptr: *mut [u8]) {
+        drop(alloc::boxed::Box::from_raw(ptr));
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn test_owned() {
+    type Delete = unsafe extern "C" fn(*mut u32);
+    assert_send_sync::<SyncOwnedPtr<u32, Delete>>();
+    assert_send::<SendOwnedPtr<u32, Delete>>();
+
+    let deleted = DELETED.load(std::sync::atomic::Ordering::SeqCst);
+    let boxed = || alloc::boxed::Box::into_raw(alloc::boxed::Box::new(5u32));
+
+    let owned = unsafe { SyncOwnedPtr::new(boxed(), delete_boxed as Delete) };
+    assert_eq!(format!("{:?}", owned), format!("SyncOwnedPtr({:p})", owned.inner()));
+    std::thread::scope(|s| {
+        s.spawn(|| assert_eq!(unsafe { owned.as_const_ptr().read() }, 5));
+        s.spawn(|| assert_eq!(unsafe { owned.as_ptr().read() }, 5));
+    });
+    std::thread::spawn(move || drop(owned)).join().unwrap();
+    assert_eq!(DELETED.load(std::sync::atomic::Ordering::SeqCst), deleted + 1);
+
+    drop(unsafe { SendOwnedPtr::new(core::ptr::null_mut(), delete_boxed as Delete) });
+    let raw = unsafe { SendOwnedPtr::new(boxed(), delete_boxed as Delete) }.into_raw();
+    let leaked = unsafe { SendOwnedPtr::new(boxed(), delete_boxed as Delete) }.leak();
+    assert_eq!(DELETED.load(std::sync::atomic::Ordering::SeqCst), deleted + 1);
+    unsafe {
+        delete_boxed(raw);
+        delete_boxed(leaked.inner());
+    }
+
+    let count = core::cell::Cell::new(0);
+    let slice: alloc::boxed::Box<[u8]> = alloc::boxed::Box::new([1, 2, 3]);
+    let slice = alloc::boxed::Box::into_raw(slice);
+    let owned = unsafe { SendOwnedPtr::new(slice, CountingDeleter(&count)) };
+    assert_eq!(owned.as_const_ptr().len(), 3);
+    assert_eq!(owned.deleter().0.get(), 0);
+    drop(owned);
+    assert_eq!(count.get(), 1);
+}